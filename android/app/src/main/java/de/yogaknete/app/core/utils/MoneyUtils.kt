@@ -0,0 +1,42 @@
+package de.yogaknete.app.core.utils
+
+import java.math.BigDecimal
+import java.math.RoundingMode
+
+object MoneyUtils {
+
+    /**
+     * Calculate the amount of a single invoice line in cents.
+     * Durations are whole minutes stored as hours, so the exact amount is
+     * rateCents * minutes / 60, rounded half-up to whole cents.
+     */
+    fun lineAmountCents(durationHours: Double, hourlyRate: Double): Long {
+        val minutes = Math.round(durationHours * 60)
+        val rateCents = Math.round(hourlyRate * 100)
+        return BigDecimal.valueOf(rateCents * minutes)
+            .divide(BigDecimal.valueOf(60), 0, RoundingMode.HALF_UP)
+            .longValueExact()
+    }
+
+    /**
+     * Calculate the invoice total in cents as the sum of the rounded line amounts,
+     * so the printed lines always add up to the printed total
+     */
+    fun totalAmountCents(durationsHours: List<Double>, hourlyRate: Double): Long {
+        return durationsHours.sumOf { lineAmountCents(it, hourlyRate) }
+    }
+
+    /**
+     * Calculate the invoice total in EUR (see [totalAmountCents])
+     */
+    fun totalAmount(durationsHours: List<Double>, hourlyRate: Double): Double {
+        return centsToEuros(totalAmountCents(durationsHours, hourlyRate))
+    }
+
+    /**
+     * Convert an amount in cents to EUR
+     */
+    fun centsToEuros(cents: Long): Double {
+        return BigDecimal.valueOf(cents).movePointLeft(2).toDouble()
+    }
+}
@@ -22,7 +22,7 @@ import de.yogaknete.app.data.local.dao.ClassTemplateDao
         ClassTemplate::class,
         Invoice::class
     ],
-    version = 6,
+    version = 9,
     exportSchema = false
 )
 @TypeConverters(DateTimeConverters::class)
@@ -43,5 +43,24 @@ abstract class AppDatabase : RoomDatabase() {
                 db.execSQL("ALTER TABLE class_templates ADD COLUMN referenceDate TEXT DEFAULT NULL")
             }
         }
+
+        val MIGRATION_6_7 = object : Migration(6, 7) {
+            override fun migrate(db: SupportSQLiteDatabase) {
+                db.execSQL("ALTER TABLE user_profile ADD COLUMN isKleinunternehmer INTEGER NOT NULL DEFAULT 0")
+            }
+        }
+
+        val MIGRATION_7_8 = object : Migration(7, 8) {
+            override fun migrate(db: SupportSQLiteDatabase) {
+                db.execSQL("ALTER TABLE studios ADD COLUMN invoiceReference TEXT NOT NULL DEFAULT ''")
+                db.execSQL("ALTER TABLE invoices ADD COLUMN reference TEXT NOT NULL DEFAULT ''")
+            }
+        }
+
+        val MIGRATION_8_9 = object : Migration(8, 9) {
+            override fun migrate(db: SupportSQLiteDatabase) {
+                db.execSQL("ALTER TABLE invoices ADD COLUMN customerNote TEXT NOT NULL DEFAULT ''")
+            }
+        }
     }
 }
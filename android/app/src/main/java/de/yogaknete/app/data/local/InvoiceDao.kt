@@ -49,7 +49,9 @@ interface InvoiceDao {
     
     /**
      * Get summary of completed classes for invoice generation
-     * This query calculates totals from yoga_classes table for each studio in a given month
+     * This query calculates totals from yoga_classes table for each studio in a given month.
+     * Existing invoices keep the hourly rate stored at creation. totalAmount is left at 0 here,
+     * InvoiceRepositoryImpl fills it from the rounded line amounts (MoneyUtils).
      */
     @Query("""
         SELECT 
@@ -59,8 +61,8 @@ interface InvoiceDao {
             :year as year,
             COALESCE(SUM(yc.durationHours), 0.0) as totalHours,
             COUNT(yc.id) as completedClasses,
-            s.hourlyRate as hourlyRate,
-            COALESCE(SUM(yc.durationHours), 0.0) * s.hourlyRate as totalAmount,
+            COALESCE(i.hourlyRate, s.hourlyRate) as hourlyRate,
+            0.0 as totalAmount,
             CASE WHEN i.id IS NOT NULL THEN 1 ELSE 0 END as hasExistingInvoice,
             i.id as invoiceId,
             i.paymentStatus as paymentStatus
@@ -73,7 +75,7 @@ interface InvoiceDao {
             AND i.month = :month 
             AND i.year = :year
         WHERE s.isActive = 1 OR i.id IS NOT NULL
-        GROUP BY s.id, s.name, s.hourlyRate, i.id, i.paymentStatus
+        GROUP BY s.id, s.name, s.hourlyRate, i.id, i.hourlyRate, i.paymentStatus
         HAVING COUNT(yc.id) > 0 OR i.id IS NOT NULL
         ORDER BY s.name
     """)
@@ -2,6 +2,7 @@ package de.yogaknete.app.data.local
 
 import androidx.room.*
 import de.yogaknete.app.domain.model.Studio
+import de.yogaknete.app.domain.model.StudioDeletePreview
 import kotlinx.coroutines.flow.Flow
 
 @Dao
@@ -31,6 +32,17 @@ interface StudioDao {
     @Query("UPDATE studios SET isActive = 0 WHERE id = :id")
     suspend fun deactivateStudio(id: Long)
     
+    /**
+     * Count the classes, templates and invoices that are deleted along with the studio
+     */
+    @Query("""
+        SELECT
+            (SELECT COUNT(*) FROM yoga_classes WHERE studioId = :id) as classCount,
+            (SELECT COUNT(*) FROM class_templates WHERE studioId = :id) as templateCount,
+            (SELECT COUNT(*) FROM invoices WHERE studioId = :id) as invoiceCount
+    """)
+    suspend fun getDeletePreview(id: Long): StudioDeletePreview
+    
     @Query("SELECT COUNT(*) FROM studios WHERE isActive = 1")
     suspend fun getActiveStudioCount(): Int
     
@@ -2,6 +2,7 @@ package de.yogaknete.app.data.local
 
 import androidx.room.*
 import de.yogaknete.app.domain.model.YogaClass
+import de.yogaknete.app.domain.model.ClassTitleUsage
 import de.yogaknete.app.domain.model.ClassStatus
 import kotlinx.coroutines.flow.Flow
 import kotlinx.datetime.LocalDateTime
@@ -48,6 +49,21 @@ interface YogaClassDao {
     """)
     suspend fun getClassAtTime(startTime: LocalDateTime, studioId: Long): YogaClass?
     
+    /**
+     * Get a class that is not cancelled at the same studio and start time, ignoring [excludeId].
+     * Such a class would be billed twice.
+     */
+    @Query("""
+        SELECT * FROM yoga_classes 
+        WHERE date(startTime) = date(:startTime) 
+        AND time(startTime) = time(:startTime)
+        AND studioId = :studioId
+        AND status != 'CANCELLED'
+        AND id != :excludeId
+        LIMIT 1
+    """)
+    suspend fun getActiveClassAtTime(startTime: LocalDateTime, studioId: Long, excludeId: Long): YogaClass?
+    
     @Query("""
         SELECT * FROM yoga_classes 
         WHERE studioId = :studioId 
@@ -57,6 +73,19 @@ interface YogaClassDao {
     """)
     suspend fun getClassesForStudioInMonth(studioId: Long, month: Int, year: Int): List<YogaClass>
     
+    /**
+     * Get past class titles per studio, most frequently used first.
+     * Titles differing only in surrounding spaces or case count as one.
+     */
+    @Query("""
+        SELECT studioId, TRIM(title) as title, COUNT(*) as usageCount
+        FROM yoga_classes
+        WHERE TRIM(title) != ''
+        GROUP BY studioId, TRIM(title) COLLATE NOCASE
+        ORDER BY usageCount DESC, MAX(startTime) DESC
+    """)
+    suspend fun getClassTitleUsages(): List<ClassTitleUsage>
+    
     @Query("SELECT * FROM yoga_classes")
     suspend fun getAllClassesOnce(): List<YogaClass>
     
@@ -1,5 +1,6 @@
 package de.yogaknete.app.data.repository
 
+import de.yogaknete.app.core.utils.MoneyUtils
 import de.yogaknete.app.data.local.InvoiceDao
 import de.yogaknete.app.domain.model.Invoice
 import de.yogaknete.app.domain.model.InvoiceSummary
@@ -64,7 +65,13 @@ class InvoiceRepositoryImpl @Inject constructor(
         month: Int,
         year: Int
     ): List<InvoiceSummary> {
-        return invoiceDao.getInvoiceSummariesForMonth(month, year)
+        // Compute the amount from the classes so it matches the printed invoice line by line.
+        // summary.hourlyRate is the invoice's stored rate if an invoice exists, else the studio's rate
+        return invoiceDao.getInvoiceSummariesForMonth(month, year).map { summary ->
+            val durations = invoiceDao.getClassesForInvoice(summary.studioId, month, year)
+                .map { it.durationHours }
+            summary.copy(totalAmount = MoneyUtils.totalAmount(durations, summary.hourlyRate))
+        }
     }
     
     override suspend fun getClassesForInvoice(
@@ -2,6 +2,7 @@ package de.yogaknete.app.data.repository
 
 import de.yogaknete.app.data.local.StudioDao
 import de.yogaknete.app.domain.model.Studio
+import de.yogaknete.app.domain.model.StudioDeletePreview
 import de.yogaknete.app.domain.repository.StudioRepository
 import kotlinx.coroutines.flow.Flow
 import javax.inject.Inject
@@ -40,6 +41,10 @@ class StudioRepositoryImpl @Inject constructor(
         studioDao.deleteStudio(studio)
     }
     
+    override suspend fun getDeletePreview(id: Long): StudioDeletePreview {
+        return studioDao.getDeletePreview(id)
+    }
+    
     override suspend fun deactivateStudio(id: Long) {
         studioDao.deactivateStudio(id)
     }
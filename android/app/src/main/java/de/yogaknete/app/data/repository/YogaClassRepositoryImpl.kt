@@ -38,6 +38,10 @@ class YogaClassRepositoryImpl @Inject constructor(
         return yogaClassDao.getClassById(id)
     }
     
+    override suspend fun getActiveClassAtTime(startTime: LocalDateTime, studioId: Long): YogaClass? {
+        return yogaClassDao.getActiveClassAtTime(startTime, studioId, excludeId = 0)
+    }
+    
     override suspend fun getClassesForInvoice(studioId: Long, month: Int, year: Int): List<YogaClass> {
         return yogaClassDao.getClassesForStudioInMonth(studioId, month, year)
     }
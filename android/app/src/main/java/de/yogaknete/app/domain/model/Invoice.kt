@@ -30,17 +30,19 @@ data class Invoice(
     @PrimaryKey(autoGenerate = true)
     val id: Long = 0,
     val studioId: Long,
-    val invoiceNumber: String, // Format: YYYY-MM-XXX (e.g., 2024-03-001)
+    val invoiceNumber: String, // Format: YYYY-XXX (e.g., 2025-005)
     val month: Int, // 1-12
     val year: Int,
     val totalHours: Double,
     val hourlyRate: Double, // Store the rate at time of invoice creation
-    val totalAmount: Double, // totalHours * hourlyRate
+    val totalAmount: Double, // Sum of the rounded line amounts, see MoneyUtils
     val paymentStatus: PaymentStatus = PaymentStatus.PENDING,
     val createdAt: LocalDateTime,
     val paidAt: LocalDateTime? = null,
     val notes: String = "",
-    val pdfPath: String? = null // Path to generated PDF file
+    val pdfPath: String? = null, // Path to generated PDF file
+    val reference: String = "", // Client's reference, printed as "Ihre Referenz", copied from the studio on creation
+    val customerNote: String = "" // Printed on the invoice, unlike notes
 )
 
 @Serializable
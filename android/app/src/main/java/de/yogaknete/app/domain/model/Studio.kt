@@ -17,5 +17,18 @@ data class Studio(
     val postalCode: String = "",
     val city: String = "",
     val hourlyRate: Double, // EUR per hour
-    val isActive: Boolean = true
+    val isActive: Boolean = true,
+    val invoiceReference: String = "" // Default reference for new invoices, e.g. purchase order number
 )
+
+/**
+ * Data that would be deleted together with a studio (cascading foreign keys)
+ */
+data class StudioDeletePreview(
+    val classCount: Int,
+    val templateCount: Int,
+    val invoiceCount: Int
+) {
+    val hasDependents: Boolean
+        get() = classCount > 0 || templateCount > 0 || invoiceCount > 0
+}
@@ -19,5 +19,6 @@ data class UserProfile(
     val iban: String = "",
     val bic: String = "", // Optional BIC/SWIFT code
     val defaultHourlyRate: Double, // Default hourly rate in EUR
+    val isKleinunternehmer: Boolean = false, // §19 UStG: no VAT, exemption notice on invoices
     val isOnboardingComplete: Boolean = false
 )
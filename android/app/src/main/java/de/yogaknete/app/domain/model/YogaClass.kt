@@ -49,3 +49,12 @@ enum class CreationSource {
     TEMPLATE,       // Created from template (quick-add)
     AUTO           // Auto-scheduled from recurring template
 }
+
+/**
+ * A class title used at a studio, with the number of classes that used it
+ */
+data class ClassTitleUsage(
+    val studioId: Long,
+    val title: String,
+    val usageCount: Int
+)
@@ -1,6 +1,7 @@
 package de.yogaknete.app.domain.repository
 
 import de.yogaknete.app.domain.model.Studio
+import de.yogaknete.app.domain.model.StudioDeletePreview
 import kotlinx.coroutines.flow.Flow
 
 interface StudioRepository {
@@ -19,6 +20,8 @@ interface StudioRepository {
     
     suspend fun deleteStudio(studio: Studio)
     
+    suspend fun getDeletePreview(id: Long): StudioDeletePreview
+    
     suspend fun deactivateStudio(id: Long)
     
     suspend fun getActiveStudioCount(): Int
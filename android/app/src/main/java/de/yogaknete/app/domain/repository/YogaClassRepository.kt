@@ -3,6 +3,7 @@ package de.yogaknete.app.domain.repository
 import de.yogaknete.app.domain.model.YogaClass
 import kotlinx.coroutines.flow.Flow
 import kotlinx.datetime.LocalDate
+import kotlinx.datetime.LocalDateTime
 
 interface YogaClassRepository {
     fun getClassesForWeek(startDate: LocalDate, endDate: LocalDate): Flow<List<YogaClass>>
@@ -11,6 +12,7 @@ interface YogaClassRepository {
     suspend fun updateClass(yogaClass: YogaClass)
     suspend fun deleteClass(yogaClass: YogaClass)
     suspend fun getClassById(id: Long): YogaClass?
+    suspend fun getActiveClassAtTime(startTime: LocalDateTime, studioId: Long): YogaClass?
     suspend fun getClassesForInvoice(studioId: Long, month: Int, year: Int): List<YogaClass>
     suspend fun getAllClassesOnce(): List<YogaClass>
     suspend fun insertClass(yogaClass: YogaClass): Long
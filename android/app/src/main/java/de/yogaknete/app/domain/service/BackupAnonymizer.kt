@@ -0,0 +1,75 @@
+package de.yogaknete.app.domain.service
+
+import de.yogaknete.app.domain.model.BackupData
+import de.yogaknete.app.domain.model.Studio
+import de.yogaknete.app.domain.model.UserProfile
+import javax.inject.Inject
+
+/**
+ * Replaces personal data in a backup with placeholder values.
+ *
+ * Class titles and template names are free text and are replaced as well.
+ * IDs, dates, durations, rates and amounts are kept unchanged, so an anonymized
+ * backup still reproduces the same classes and invoices when attached to a bug report.
+ */
+class BackupAnonymizer @Inject constructor() {
+
+    fun anonymize(backupData: BackupData): BackupData {
+        // Same title gets the same placeholder, so classes still match their templates
+        val titles = (backupData.classes.map { it.title } + backupData.templates.map { it.className })
+            .distinct()
+            .withIndex()
+            .associate { (index, title) -> title to "Kurs ${index + 1}" }
+
+        return backupData.copy(
+            userProfile = backupData.userProfile?.let { anonymizeProfile(it) },
+            studios = backupData.studios.mapIndexed { index, studio -> anonymizeStudio(studio, index + 1) },
+            classes = backupData.classes.map { it.copy(title = titles.getValue(it.title), notes = "") },
+            templates = backupData.templates.mapIndexed { index, template ->
+                template.copy(name = "Vorlage ${index + 1}", className = titles.getValue(template.className))
+            },
+            invoices = backupData.invoices.map {
+                it.copy(
+                    notes = "",
+                    customerNote = "",
+                    pdfPath = null,
+                    reference = if (it.reference.isNotEmpty()) "REF-${it.studioId}" else ""
+                )
+            }
+        )
+    }
+
+    private fun anonymizeProfile(profile: UserProfile): UserProfile {
+        return profile.copy(
+            name = "Erika Mustermann",
+            street = if (profile.street.isNotEmpty()) "Musterstraße 1" else "",
+            postalCode = if (profile.postalCode.isNotEmpty()) "12345" else "",
+            city = if (profile.city.isNotEmpty()) "Musterstadt" else "",
+            taxId = if (profile.taxId.isNotEmpty()) "000/000/00000" else "",
+            phone = if (profile.phone.isNotEmpty()) "0000 0000000" else "",
+            email = if (profile.email.isNotEmpty()) "erika@example.com" else "",
+            bankName = if (profile.bankName.isNotEmpty()) "Musterbank" else "",
+            iban = if (profile.iban.isNotEmpty()) PLACEHOLDER_IBAN else "",
+            bic = if (profile.bic.isNotEmpty()) PLACEHOLDER_BIC else ""
+        )
+    }
+
+    private fun anonymizeStudio(studio: Studio, number: Int): Studio {
+        return studio.copy(
+            name = "Studio $number",
+            contactPerson = if (studio.contactPerson.isNotEmpty()) "Kontakt $number" else "",
+            email = if (studio.email.isNotEmpty()) "studio$number@example.com" else "",
+            phone = if (studio.phone.isNotEmpty()) "0000 0000000" else "",
+            street = if (studio.street.isNotEmpty()) "Studiostraße $number" else "",
+            postalCode = if (studio.postalCode.isNotEmpty()) "12345" else "",
+            city = if (studio.city.isNotEmpty()) "Musterstadt" else "",
+            invoiceReference = if (studio.invoiceReference.isNotEmpty()) "REF-${studio.id}" else ""
+        )
+    }
+
+    companion object {
+        // Publicly documented example account, valid checksum so the GiroCode still renders
+        const val PLACEHOLDER_IBAN = "DE89370400440532013000"
+        const val PLACEHOLDER_BIC = "COBADEFFXXX"
+    }
+}
@@ -25,7 +25,8 @@ class BackupExportService @Inject constructor(
     private val studioRepository: StudioRepository,
     private val classRepository: YogaClassRepository,
     private val templateRepository: ClassTemplateRepository,
-    private val invoiceRepository: InvoiceRepository
+    private val invoiceRepository: InvoiceRepository,
+    private val backupAnonymizer: BackupAnonymizer
 ) {
     private val json = Json {
         prettyPrint = true
@@ -33,7 +34,11 @@ class BackupExportService @Inject constructor(
         encodeDefaults = true
     }
 
-    suspend fun exportFullBackup(): BackupResult {
+    /**
+     * Exports all data as a JSON backup to the Downloads folder.
+     * With [anonymize] set, personal data is replaced with placeholders (see [BackupAnonymizer]).
+     */
+    suspend fun exportFullBackup(anonymize: Boolean = false): BackupResult {
         return try {
             // Collect all data
             val userProfile = userProfileRepository.getUserProfileOnce()
@@ -55,7 +60,9 @@ class BackupExportService @Inject constructor(
             )
 
             // Convert to JSON
-            val jsonString = json.encodeToString(backupData)
+            val jsonString = json.encodeToString(
+                if (anonymize) backupAnonymizer.anonymize(backupData) else backupData
+            )
             
             // Generate filename with timestamp
             val timestamp = LocalDateTime.now()
@@ -63,7 +70,11 @@ class BackupExportService @Inject constructor(
                 .replace(":", "-")
                 .replace(".", "-")
                 .replace("T", "_")
-            val fileName = "yogaknete_backup_$timestamp.json"
+            val fileName = if (anonymize) {
+                "yogaknete_backup_anonym_$timestamp.json"
+            } else {
+                "yogaknete_backup_$timestamp.json"
+            }
             
             // Save to Downloads folder
             // Since minSdk is 29 (Android Q), we always use MediaStore
@@ -151,10 +151,12 @@ class BackupImportService @Inject constructor(
                 }
             }
             
-            // Import all classes (they are unique by date/time)
+            // Merge classes (skip duplicates of non-cancelled classes by studio and start time, they would be billed twice)
             backupData.classes.forEach { yogaClass ->
-                classRepository.insertClass(yogaClass.copy(id = 0))
-                importedClasses++
+                if (classRepository.getActiveClassAtTime(yogaClass.startTime, yogaClass.studioId) == null) {
+                    classRepository.insertClass(yogaClass.copy(id = 0))
+                    importedClasses++
+                }
             }
             
             // Merge invoices (skip duplicates by invoice number)
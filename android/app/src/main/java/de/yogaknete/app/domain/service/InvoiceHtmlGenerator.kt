@@ -1,11 +1,16 @@
 package de.yogaknete.app.domain.service
 
+import de.yogaknete.app.core.utils.MoneyUtils
 import de.yogaknete.app.core.utils.formatAsIban
 import de.yogaknete.app.domain.model.Invoice
 import de.yogaknete.app.domain.model.Studio
 import de.yogaknete.app.domain.model.UserProfile
 import de.yogaknete.app.domain.model.YogaClass
+import kotlinx.datetime.DatePeriod
+import kotlinx.datetime.LocalDate
 import kotlinx.datetime.LocalDateTime
+import kotlinx.datetime.minus
+import kotlinx.datetime.plus
 import kotlinx.datetime.TimeZone
 import kotlinx.datetime.toInstant
 import java.text.DecimalFormat
@@ -31,6 +36,8 @@ class InvoiceHtmlGenerator @Inject constructor() {
             time = currentDate
             add(Calendar.DAY_OF_MONTH, 14) // 14 days payment term
         }.time
+        // Derive the total from the rounded line amounts so the printed lines add up
+        val totalAmount = MoneyUtils.totalAmount(yogaClasses.map { it.durationHours }, invoice.hourlyRate)
         
         return """
 <!DOCTYPE html>
@@ -232,6 +239,17 @@ class InvoiceHtmlGenerator @Inject constructor() {
             color: #333;
         }
         
+        .customer-note {
+            margin-bottom: 30px;
+            font-size: 14px;
+        }
+        
+        .tax-notice {
+            margin-top: 15px;
+            font-size: 13px;
+            color: #333;
+        }
+        
         .payment-section {
             margin-top: 40px;
             padding: 20px;
@@ -333,6 +351,11 @@ class InvoiceHtmlGenerator @Inject constructor() {
                 line-height: 1.4;
             }
 
+            .legal-footer {
+                font-size: 10px;
+                color: #666;
+            }
+
             .payment-footer-right {
                 text-align: right;
             }
@@ -400,6 +423,8 @@ class InvoiceHtmlGenerator @Inject constructor() {
                 <tr>
                     <td>Rechnungsnummer:</td>
                     <td>${invoice.invoiceNumber}</td>
+                    <td>Leistungszeitraum:</td>
+                    <td>${formatServicePeriod(invoice.month, invoice.year)}</td>
                 </tr>
                 <tr>
                     <td>Rechnungsdatum:</td>
@@ -407,6 +432,7 @@ class InvoiceHtmlGenerator @Inject constructor() {
                     <td>Zahlbar bis:</td>
                     <td>${dateFormat.format(dueDate)}</td>
                 </tr>
+                ${if (invoice.reference.isNotBlank()) "<tr><td>Ihre Referenz:</td><td>${invoice.reference}</td></tr>" else ""}
             </table>
         </div>
         
@@ -431,7 +457,9 @@ class InvoiceHtmlGenerator @Inject constructor() {
                     ${yogaClasses.joinToString("\n") { yogaClass ->
                         val classDate = Date(yogaClass.startTime.toInstant(TimeZone.currentSystemDefault()).toEpochMilliseconds())
                         val timeFormat = SimpleDateFormat("HH:mm", Locale.GERMANY)
-                        val amount = yogaClass.durationHours * invoice.hourlyRate
+                        val amount = MoneyUtils.centsToEuros(
+                            MoneyUtils.lineAmountCents(yogaClass.durationHours, invoice.hourlyRate)
+                        )
                         """
                     <tr>
                         <td>${dateFormat.format(classDate)}</td>
@@ -454,16 +482,19 @@ class InvoiceHtmlGenerator @Inject constructor() {
                     </tr>
                     <tr>
                         <td>Zwischensumme:</td>
-                        <td>${currencyFormat.format(invoice.totalAmount)} €</td>
+                        <td>${currencyFormat.format(totalAmount)} €</td>
                     </tr>
                     <tr class="total-row">
                         <td>Gesamtbetrag:</td>
-                        <td>${currencyFormat.format(invoice.totalAmount)} €</td>
+                        <td>${currencyFormat.format(totalAmount)} €</td>
                     </tr>
                 </table>
+                ${if (userProfile.isKleinunternehmer) "<p class=\"tax-notice\">$KLEINUNTERNEHMER_NOTICE</p>" else ""}
             </div>
         </div>
         
+        ${if (invoice.customerNote.isNotBlank()) "<div class=\"customer-note\">${invoice.customerNote.trim().replace("\n", "<br>")}</div>" else ""}
+        
         <!-- Payment information (screen only) -->
         <div class="payment-section">
             <h3 class="payment-title">Zahlungsinformationen</h3>
@@ -481,6 +512,7 @@ class InvoiceHtmlGenerator @Inject constructor() {
     <div class="payment-footer">
         <div class="payment-footer-content">
             <div class="payment-footer-left">
+                <span class="legal-footer">${formatLegalFooter(userProfile)}</span><br>
                 IBAN: ${userProfile.iban.formatAsIban()}${if (userProfile.bic.isNotEmpty()) " &middot; BIC: ${userProfile.bic}" else ""}<br>
                 Ref: ${invoice.invoiceNumber}
             </div>
@@ -505,6 +537,34 @@ class InvoiceHtmlGenerator @Inject constructor() {
         }
     }
     
+    /**
+     * Sender data required on every invoice page (§14 Abs. 4 UStG), built from the
+     * structured profile fields, e.g. "Anna Yoga · Yogastraße 1 · 80331 München · Steuernummer: 123/456/78901"
+     */
+    private fun formatLegalFooter(userProfile: UserProfile): String {
+        return listOf(
+            userProfile.name,
+            userProfile.street,
+            "${userProfile.postalCode} ${userProfile.city}".trim(),
+            if (userProfile.taxId.isNotBlank()) "Steuernummer: ${userProfile.taxId}" else ""
+        )
+            .filter { it.isNotBlank() }
+            .joinToString(" &middot; ")
+    }
+    
+    /**
+     * Format the invoiced month as service period, e.g. "01.03.–31.03.2025"
+     */
+    private fun formatServicePeriod(month: Int, year: Int): String {
+        val start = LocalDate(year, month, 1)
+        val end = start.plus(DatePeriod(months = 1)).minus(DatePeriod(days = 1))
+        return String.format(
+            Locale.GERMANY,
+            "%02d.%02d.–%02d.%02d.%04d",
+            start.dayOfMonth, start.monthNumber, end.dayOfMonth, end.monthNumber, end.year
+        )
+    }
+    
     private fun getMonthName(month: Int): String {
         return when (month) {
             1 -> "Januar"
@@ -522,4 +582,9 @@ class InvoiceHtmlGenerator @Inject constructor() {
             else -> ""
         }
     }
+    
+    companion object {
+        const val KLEINUNTERNEHMER_NOTICE =
+            "Gemäß § 19 UStG wird keine Umsatzsteuer berechnet (Kleinunternehmerregelung)."
+    }
 }
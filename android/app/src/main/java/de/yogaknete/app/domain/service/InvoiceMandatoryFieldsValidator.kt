@@ -0,0 +1,33 @@
+package de.yogaknete.app.domain.service
+
+import de.yogaknete.app.domain.model.Studio
+import de.yogaknete.app.domain.model.UserProfile
+import javax.inject.Inject
+
+/**
+ * Checks the data a German invoice must show (§14 Abs. 4 UStG):
+ * full name and address of sender and recipient, and the sender's
+ * Steuernummer or USt-IdNr.
+ */
+class InvoiceMandatoryFieldsValidator @Inject constructor() {
+
+    /**
+     * Returns German labels of all missing mandatory fields, empty if the invoice is complete
+     */
+    fun findMissingFields(userProfile: UserProfile, studio: Studio): List<String> {
+        val missing = mutableListOf<String>()
+
+        if (userProfile.name.isBlank()) missing += "Dein Name"
+        if (userProfile.street.isBlank()) missing += "Deine Straße"
+        if (userProfile.postalCode.isBlank()) missing += "Deine PLZ"
+        if (userProfile.city.isBlank()) missing += "Dein Ort"
+        if (userProfile.taxId.isBlank()) missing += "Steuernummer oder USt-IdNr."
+
+        if (studio.name.isBlank()) missing += "Studio-Name"
+        if (studio.street.isBlank()) missing += "Straße des Studios"
+        if (studio.postalCode.isBlank()) missing += "PLZ des Studios"
+        if (studio.city.isBlank()) missing += "Ort des Studios"
+
+        return missing
+    }
+}
@@ -9,6 +9,7 @@ import android.webkit.WebView
 import android.webkit.WebViewClient
 import dagger.hilt.android.qualifiers.ApplicationContext
 import de.yogaknete.app.core.utils.EpcQrCodeGenerator
+import de.yogaknete.app.core.utils.MoneyUtils
 import de.yogaknete.app.domain.model.Invoice
 import de.yogaknete.app.domain.model.Studio
 import de.yogaknete.app.domain.model.UserProfile
@@ -40,7 +41,7 @@ class InvoicePdfService @Inject constructor(
         studio: Studio,
         yogaClasses: List<YogaClass>
     ) = withContext(Dispatchers.Main) {
-        val qrCode = generateQrCode(userProfile, invoice)
+        val qrCode = generateQrCode(userProfile, invoice, yogaClasses)
         val html = htmlGenerator.generateInvoiceHtml(
             invoice = invoice,
             userProfile = userProfile,
@@ -73,7 +74,7 @@ class InvoicePdfService @Inject constructor(
                 super.onPageFinished(view, url)
                 
                 // Create print job
-                createPrintJob(activity, webView, invoice.invoiceNumber)
+                createPrintJob(activity, webView, documentName(invoice, studio))
             }
         }
     }
@@ -88,7 +89,7 @@ class InvoicePdfService @Inject constructor(
         yogaClasses: List<YogaClass>,
         outputFile: File
     ): File = withContext(Dispatchers.IO) {
-        val qrCode = generateQrCode(userProfile, invoice)
+        val qrCode = generateQrCode(userProfile, invoice, yogaClasses)
         val html = htmlGenerator.generateInvoiceHtml(
             invoice = invoice,
             userProfile = userProfile,
@@ -119,13 +120,18 @@ class InvoicePdfService @Inject constructor(
         )
     }
     
-    private fun generateQrCode(userProfile: UserProfile, invoice: Invoice): String? {
+    private fun generateQrCode(
+        userProfile: UserProfile,
+        invoice: Invoice,
+        yogaClasses: List<YogaClass>
+    ): String? {
         if (userProfile.iban.isBlank()) return null
         return qrCodeGenerator.generateBase64Png(
             bic = userProfile.bic,
             recipientName = userProfile.name,
             iban = userProfile.iban,
-            amount = invoice.totalAmount,
+            // Same total as the printed Gesamtbetrag, independent of the stored invoice amount
+            amount = MoneyUtils.totalAmount(yogaClasses.map { it.durationHours }, invoice.hourlyRate),
             reference = invoice.invoiceNumber
         )
     }
@@ -147,4 +153,19 @@ class InvoicePdfService @Inject constructor(
             printAttributes
         )
     }
+
+    companion object {
+        /**
+         * Descriptive document name, used by "Als PDF speichern" as default file name,
+         * e.g. "Rechnung_2025-005_Yoga_Studio_Zen"
+         */
+        fun documentName(invoice: Invoice, studio: Studio): String {
+            val studioPart = studio.name
+                .replace(Regex("[^\\p{L}\\p{N}-]+"), "_")
+                .trim('_')
+            return listOf("Rechnung", invoice.invoiceNumber, studioPart)
+                .filter { it.isNotEmpty() }
+                .joinToString("_")
+        }
+    }
 }
@@ -18,6 +18,7 @@ import androidx.compose.material.icons.outlined.CloudUpload
 import androidx.compose.material.icons.outlined.Schedule
 import androidx.compose.material.icons.outlined.Share
 import androidx.compose.material.icons.outlined.CloudDownload
+import androidx.compose.material.icons.outlined.VisibilityOff
 import androidx.compose.material3.*
 import androidx.compose.runtime.*
 import androidx.compose.ui.Alignment
@@ -87,6 +88,7 @@ fun BackupManagementScreen(
                 ExportSection(
                     onExportJson = { viewModel.exportBackup(BackupFormat.JSON) },
                     onExportZip = { viewModel.exportBackup(BackupFormat.ZIP) },
+                    onExportAnonymized = { viewModel.exportBackup(BackupFormat.JSON, anonymize = true) },
                     lastBackupResult = uiState.lastBackupResult
                 )
                 
@@ -182,6 +184,7 @@ fun BackupManagementScreen(
 private fun ExportSection(
     onExportJson: () -> Unit,
     onExportZip: () -> Unit,
+    onExportAnonymized: () -> Unit,
     lastBackupResult: BackupResult.Success?
 ) {
     Card(
@@ -248,6 +251,15 @@ private fun ExportSection(
                 }
             }
             
+            TextButton(
+                onClick = onExportAnonymized,
+                modifier = Modifier.fillMaxWidth()
+            ) {
+                Icon(Icons.Outlined.VisibilityOff, contentDescription = null)
+                Spacer(modifier = Modifier.width(4.dp))
+                Text("Anonymisiert exportieren (für Fehlerberichte)")
+            }
+            
             if (lastBackupResult != null) {
                 Spacer(modifier = Modifier.height(8.dp))
                 Text(
@@ -22,13 +22,13 @@ class BackupViewModel @Inject constructor(
     private val _uiState = MutableStateFlow(BackupUiState())
     val uiState: StateFlow<BackupUiState> = _uiState.asStateFlow()
     
-    fun exportBackup(format: BackupFormat) {
+    fun exportBackup(format: BackupFormat, anonymize: Boolean = false) {
         viewModelScope.launch {
             _uiState.value = _uiState.value.copy(isProcessing = true)
             
             val result = when (format) {
-                BackupFormat.JSON -> backupExportService.exportFullBackup()
-                BackupFormat.ZIP -> backupExportService.exportFullBackup() // TODO: Implement ZIP
+                BackupFormat.JSON -> backupExportService.exportFullBackup(anonymize)
+                BackupFormat.ZIP -> backupExportService.exportFullBackup(anonymize) // TODO: Implement ZIP
             }
             
             when (result) {
@@ -9,6 +9,7 @@ import androidx.compose.material.icons.automirrored.filled.ArrowBack
 import androidx.compose.material.icons.filled.Share
 import androidx.compose.material.icons.filled.CheckCircle
 import androidx.compose.material.icons.filled.DateRange
+import androidx.compose.material.icons.filled.Warning
 import androidx.compose.material3.*
 import androidx.compose.runtime.*
 import androidx.compose.ui.Alignment
@@ -210,6 +211,44 @@ fun InvoiceDetailScreen(
                     }
                 }
                 
+                // Missing mandatory invoice data (§14 UStG)
+                if (uiState.missingMandatoryFields.isNotEmpty()) {
+                    Card(
+                        modifier = Modifier
+                            .fillMaxWidth()
+                            .padding(horizontal = 16.dp)
+                            .padding(bottom = 16.dp),
+                        colors = CardDefaults.cardColors(
+                            containerColor = MaterialTheme.colorScheme.errorContainer
+                        )
+                    ) {
+                        Row(
+                            modifier = Modifier.padding(16.dp),
+                            verticalAlignment = Alignment.Top
+                        ) {
+                            Icon(
+                                Icons.Default.Warning,
+                                contentDescription = null,
+                                tint = MaterialTheme.colorScheme.onErrorContainer
+                            )
+                            Spacer(modifier = Modifier.width(12.dp))
+                            Column {
+                                Text(
+                                    text = "Pflichtangaben fehlen",
+                                    style = MaterialTheme.typography.titleSmall,
+                                    fontWeight = FontWeight.Bold,
+                                    color = MaterialTheme.colorScheme.onErrorContainer
+                                )
+                                Text(
+                                    text = "Ergänze vor dem Versand: ${uiState.missingMandatoryFields.joinToString(", ")}",
+                                    style = MaterialTheme.typography.bodyMedium,
+                                    color = MaterialTheme.colorScheme.onErrorContainer
+                                )
+                            }
+                        }
+                    }
+                }
+                
                 // Sender and Receiver Information
                 Row(
                     modifier = Modifier
@@ -429,6 +468,42 @@ fun InvoiceDetailScreen(
                 
                 Spacer(modifier = Modifier.height(16.dp))
                 
+                // Customer note (printed on the invoice)
+                var customerNote by remember(uiState.invoice?.customerNote) {
+                    mutableStateOf(uiState.invoice?.customerNote ?: "")
+                }
+                Card(
+                    modifier = Modifier
+                        .fillMaxWidth()
+                        .padding(horizontal = 16.dp),
+                    colors = CardDefaults.cardColors(
+                        containerColor = MaterialTheme.colorScheme.surface
+                    )
+                ) {
+                    Column(
+                        modifier = Modifier.padding(16.dp)
+                    ) {
+                        OutlinedTextField(
+                            value = customerNote,
+                            onValueChange = { customerNote = it },
+                            label = { Text("Hinweis auf der Rechnung") },
+                            placeholder = { Text("z.B. Vielen Dank für die Zusammenarbeit!") },
+                            modifier = Modifier.fillMaxWidth(),
+                            minLines = 2
+                        )
+                        if (customerNote.trim() != uiState.invoice?.customerNote) {
+                            TextButton(
+                                onClick = { viewModel.updateCustomerNote(customerNote) },
+                                modifier = Modifier.align(Alignment.End)
+                            ) {
+                                Text("Hinweis speichern")
+                            }
+                        }
+                    }
+                }
+                
+                Spacer(modifier = Modifier.height(16.dp))
+                
                 // Bank Details
                 uiState.userProfile?.let { profile ->
                     if (profile.iban.isNotEmpty()) {
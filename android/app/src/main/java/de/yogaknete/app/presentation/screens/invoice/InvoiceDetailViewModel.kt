@@ -4,6 +4,7 @@ import androidx.lifecycle.SavedStateHandle
 import androidx.lifecycle.ViewModel
 import androidx.lifecycle.viewModelScope
 import dagger.hilt.android.lifecycle.HiltViewModel
+import de.yogaknete.app.core.utils.MoneyUtils
 import de.yogaknete.app.domain.model.Invoice
 import de.yogaknete.app.domain.model.Studio
 import de.yogaknete.app.domain.model.UserProfile
@@ -12,6 +13,7 @@ import de.yogaknete.app.domain.repository.InvoiceRepository
 import de.yogaknete.app.domain.repository.StudioRepository
 import de.yogaknete.app.domain.repository.UserProfileRepository
 import de.yogaknete.app.domain.repository.YogaClassRepository
+import de.yogaknete.app.domain.service.InvoiceMandatoryFieldsValidator
 import de.yogaknete.app.domain.service.InvoicePdfService
 import kotlinx.coroutines.flow.*
 import kotlinx.coroutines.launch
@@ -24,6 +26,7 @@ data class InvoiceDetailUiState(
     val yogaClasses: List<YogaClass> = emptyList(),
     val actualTotalHours: Double = 0.0,  // Calculated from actual classes
     val actualTotalAmount: Double = 0.0, // Calculated from actual classes
+    val missingMandatoryFields: List<String> = emptyList(),
     val isLoading: Boolean = true,
     val error: String? = null,
     val isPdfGenerating: Boolean = false,
@@ -37,7 +40,8 @@ class InvoiceDetailViewModel @Inject constructor(
     private val studioRepository: StudioRepository,
     private val userProfileRepository: UserProfileRepository,
     private val yogaClassRepository: YogaClassRepository,
-    private val pdfService: InvoicePdfService
+    private val pdfService: InvoicePdfService,
+    private val mandatoryFieldsValidator: InvoiceMandatoryFieldsValidator
 ) : ViewModel() {
     
     private val invoiceId: Long = checkNotNull(savedStateHandle.get<String>("invoiceId")?.toLongOrNull())
@@ -81,7 +85,16 @@ class InvoiceDetailViewModel @Inject constructor(
                 
                 // Calculate actual totals from the classes
                 val actualTotalHours = yogaClasses.sumOf { it.durationHours }
-                val actualTotalAmount = actualTotalHours * invoice.hourlyRate
+                val actualTotalAmount = MoneyUtils.totalAmount(
+                    yogaClasses.map { it.durationHours },
+                    invoice.hourlyRate
+                )
+                
+                val missingMandatoryFields = if (userProfile != null && studio != null) {
+                    mandatoryFieldsValidator.findMissingFields(userProfile, studio)
+                } else {
+                    emptyList()
+                }
                 
                 _uiState.update { 
                     it.copy(
@@ -91,6 +104,7 @@ class InvoiceDetailViewModel @Inject constructor(
                         yogaClasses = yogaClasses,
                         actualTotalHours = actualTotalHours,
                         actualTotalAmount = actualTotalAmount,
+                        missingMandatoryFields = missingMandatoryFields,
                         isLoading = false
                     )
                 }
@@ -143,6 +157,21 @@ class InvoiceDetailViewModel @Inject constructor(
         }
     }
     
+    fun updateCustomerNote(note: String) {
+        viewModelScope.launch {
+            val invoice = _uiState.value.invoice ?: return@launch
+            try {
+                val updated = invoice.copy(customerNote = note.trim())
+                invoiceRepository.updateInvoice(updated)
+                _uiState.update { it.copy(invoice = updated) }
+            } catch (e: Exception) {
+                _uiState.update {
+                    it.copy(error = "Fehler beim Speichern des Hinweises: ${e.message}")
+                }
+            }
+        }
+    }
+    
     fun clearError() {
         _uiState.update { it.copy(error = null) }
     }
@@ -118,6 +118,7 @@ class InvoiceViewModel @Inject constructor(
                     year = summary.year,
                     month = summary.month
                 )
+                val studio = studioRepository.getStudioById(summary.studioId)
                 
                 val invoice = Invoice(
                     studioId = summary.studioId,
@@ -128,7 +129,8 @@ class InvoiceViewModel @Inject constructor(
                     hourlyRate = summary.hourlyRate,
                     totalAmount = summary.totalAmount,
                     paymentStatus = PaymentStatus.PENDING,
-                    createdAt = Clock.System.now().toLocalDateTime(TimeZone.currentSystemDefault())
+                    createdAt = Clock.System.now().toLocalDateTime(TimeZone.currentSystemDefault()),
+                    reference = studio?.invoiceReference ?: ""
                 )
                 
                 val invoiceId = invoiceRepository.createInvoice(invoice)
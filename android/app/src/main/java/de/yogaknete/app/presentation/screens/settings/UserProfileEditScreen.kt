@@ -50,6 +50,9 @@ fun UserProfileEditScreen(
     var bankName by remember(uiState.profile) { mutableStateOf(uiState.profile?.bankName ?: "") }
     var iban by remember(uiState.profile) { mutableStateOf(uiState.profile?.iban ?: "") }
     var bic by remember(uiState.profile) { mutableStateOf(uiState.profile?.bic ?: "") }
+    var isKleinunternehmer by remember(uiState.profile) {
+        mutableStateOf(uiState.profile?.isKleinunternehmer ?: false)
+    }
     var hourlyRateText by remember(uiState.profile) { 
         mutableStateOf(uiState.profile?.defaultHourlyRate?.toString()?.replace(".", ",") ?: "")
     }
@@ -107,6 +110,7 @@ fun UserProfileEditScreen(
                                     iban = trimmedIban,
                                     bic = bic.trim().uppercase(),
                                     defaultHourlyRate = hourlyRate!!,
+                                    isKleinunternehmer = isKleinunternehmer,
                                     isOnboardingComplete = true
                                 )
                                 viewModel.saveProfile(updatedProfile)
@@ -237,6 +241,29 @@ fun UserProfileEditScreen(
                     singleLine = true
                 )
                 
+                Spacer(modifier = Modifier.height(8.dp))
+                
+                Row(
+                    modifier = Modifier.fillMaxWidth(),
+                    verticalAlignment = Alignment.CenterVertically
+                ) {
+                    Column(modifier = Modifier.weight(1f)) {
+                        Text(
+                            text = "Kleinunternehmer (§ 19 UStG)",
+                            style = MaterialTheme.typography.bodyLarge
+                        )
+                        Text(
+                            text = "Rechnungen enthalten den Hinweis, dass keine Umsatzsteuer berechnet wird",
+                            style = MaterialTheme.typography.bodySmall,
+                            color = MaterialTheme.colorScheme.onSurfaceVariant
+                        )
+                    }
+                    Switch(
+                        checked = isKleinunternehmer,
+                        onCheckedChange = { isKleinunternehmer = it }
+                    )
+                }
+                
                 Spacer(modifier = Modifier.height(16.dp))
                 
                 OutlinedTextField(
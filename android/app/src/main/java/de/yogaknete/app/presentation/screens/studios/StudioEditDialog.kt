@@ -35,7 +35,8 @@ fun StudioEditDialog(
         street: String,
         postalCode: String,
         city: String,
-        hourlyRate: Double
+        hourlyRate: Double,
+        invoiceReference: String
     ) -> Unit
 ) {
     val isEditMode = studio != null
@@ -48,6 +49,7 @@ fun StudioEditDialog(
     var street by remember { mutableStateOf(studio?.street ?: "") }
     var postalCode by remember { mutableStateOf(studio?.postalCode ?: "") }
     var city by remember { mutableStateOf(studio?.city ?: "") }
+    var invoiceReference by remember { mutableStateOf(studio?.invoiceReference ?: "") }
     var hourlyRateText by remember { 
         mutableStateOf(studio?.hourlyRate?.toString()?.replace(".", ",") ?: "")
     }
@@ -105,7 +107,8 @@ fun StudioEditDialog(
                                         street.trim(),
                                         postalCode.trim(),
                                         city.trim(),
-                                        hourlyRate!!
+                                        hourlyRate!!,
+                                        invoiceReference.trim()
                                     )
                                 }
                             }
@@ -284,6 +287,25 @@ fun StudioEditDialog(
                                 singleLine = true
                             )
                         }
+                        
+                        HorizontalDivider()
+                        
+                        // Invoice
+                        Text(
+                            text = "Rechnung",
+                            style = MaterialTheme.typography.titleMedium,
+                            fontWeight = FontWeight.Bold
+                        )
+                        
+                        OutlinedTextField(
+                            value = invoiceReference,
+                            onValueChange = { invoiceReference = it },
+                            label = { Text("Ihre Referenz") },
+                            placeholder = { Text("z.B. Bestellnummer") },
+                            supportingText = { Text("Wird auf neuen Rechnungen für dieses Studio gedruckt") },
+                            modifier = Modifier.fillMaxWidth(),
+                            singleLine = true
+                        )
                     }
                 }
             }
@@ -4,6 +4,7 @@ import androidx.lifecycle.ViewModel
 import androidx.lifecycle.viewModelScope
 import dagger.hilt.android.lifecycle.HiltViewModel
 import de.yogaknete.app.domain.model.Studio
+import de.yogaknete.app.domain.model.StudioDeletePreview
 import de.yogaknete.app.domain.repository.StudioRepository
 import kotlinx.coroutines.flow.*
 import kotlinx.coroutines.launch
@@ -15,6 +16,8 @@ data class StudioUiState(
     val error: String? = null,
     val showAddDialog: Boolean = false,
     val editingStudio: Studio? = null,
+    val deletingStudio: Studio? = null,
+    val deletePreview: StudioDeletePreview? = null,
     val successMessage: String? = null
 )
 
@@ -68,7 +71,8 @@ class StudioViewModel @Inject constructor(
         street: String,
         postalCode: String,
         city: String,
-        hourlyRate: Double
+        hourlyRate: Double,
+        invoiceReference: String = ""
     ) {
         viewModelScope.launch {
             try {
@@ -80,7 +84,8 @@ class StudioViewModel @Inject constructor(
                     street = street,
                     postalCode = postalCode,
                     city = city,
-                    hourlyRate = hourlyRate
+                    hourlyRate = hourlyRate,
+                    invoiceReference = invoiceReference
                 )
                 studioRepository.saveStudio(studio)
                 _uiState.update { 
@@ -106,7 +111,8 @@ class StudioViewModel @Inject constructor(
         street: String,
         postalCode: String,
         city: String,
-        hourlyRate: Double
+        hourlyRate: Double,
+        invoiceReference: String = ""
     ) {
         viewModelScope.launch {
             try {
@@ -118,7 +124,8 @@ class StudioViewModel @Inject constructor(
                     street = street,
                     postalCode = postalCode,
                     city = city,
-                    hourlyRate = hourlyRate
+                    hourlyRate = hourlyRate,
+                    invoiceReference = invoiceReference
                 )
                 studioRepository.updateStudio(updatedStudio)
                 _uiState.update { 
@@ -135,12 +142,40 @@ class StudioViewModel @Inject constructor(
         }
     }
     
+    fun requestDeleteStudio(studio: Studio) {
+        viewModelScope.launch {
+            try {
+                val preview = studioRepository.getDeletePreview(studio.id)
+                _uiState.update { 
+                    it.copy(deletingStudio = studio, deletePreview = preview)
+                }
+            } catch (e: Exception) {
+                // Still allow deleting, the dialog then warns without counts
+                _uiState.update { 
+                    it.copy(
+                        deletingStudio = studio,
+                        deletePreview = null,
+                        error = "Fehler beim Laden der Löschvorschau: ${e.message}"
+                    )
+                }
+            }
+        }
+    }
+    
+    fun cancelDeleteStudio() {
+        _uiState.update { it.copy(deletingStudio = null, deletePreview = null) }
+    }
+    
     fun deleteStudio(studio: Studio) {
         viewModelScope.launch {
             try {
                 studioRepository.deleteStudio(studio)
                 _uiState.update { 
-                    it.copy(successMessage = "Studio erfolgreich gelöscht")
+                    it.copy(
+                        deletingStudio = null,
+                        deletePreview = null,
+                        successMessage = "Studio erfolgreich gelöscht"
+                    )
                 }
             } catch (e: Exception) {
                 _uiState.update { 
@@ -21,6 +21,7 @@ import androidx.compose.ui.unit.dp
 import androidx.hilt.navigation.compose.hiltViewModel
 import androidx.navigation.NavController
 import de.yogaknete.app.domain.model.Studio
+import de.yogaknete.app.domain.model.StudioDeletePreview
 import kotlinx.coroutines.launch
 
 @OptIn(ExperimentalMaterial3Api::class)
@@ -135,7 +136,7 @@ fun StudiosManagementScreen(
                             studio = studio,
                             onEdit = { viewModel.showEditDialog(studio) },
                             onToggleActive = { viewModel.toggleStudioActive(studio) },
-                            onDelete = { viewModel.deleteStudio(studio) }
+                            onDelete = { viewModel.requestDeleteStudio(studio) }
                         )
                     }
                 }
@@ -156,7 +157,7 @@ fun StudiosManagementScreen(
                             studio = studio,
                             onEdit = { viewModel.showEditDialog(studio) },
                             onToggleActive = { viewModel.toggleStudioActive(studio) },
-                            onDelete = { viewModel.deleteStudio(studio) }
+                            onDelete = { viewModel.requestDeleteStudio(studio) }
                         )
                     }
                 }
@@ -169,8 +170,8 @@ fun StudiosManagementScreen(
         StudioEditDialog(
             studio = null,
             onDismiss = { viewModel.hideAddDialog() },
-            onSave = { name, contactPerson, email, phone, street, postalCode, city, hourlyRate ->
-                viewModel.addStudio(name, contactPerson, email, phone, street, postalCode, city, hourlyRate)
+            onSave = { name, contactPerson, email, phone, street, postalCode, city, hourlyRate, invoiceReference ->
+                viewModel.addStudio(name, contactPerson, email, phone, street, postalCode, city, hourlyRate, invoiceReference)
             }
         )
     }
@@ -179,11 +180,21 @@ fun StudiosManagementScreen(
         StudioEditDialog(
             studio = studio,
             onDismiss = { viewModel.hideEditDialog() },
-            onSave = { name, contactPerson, email, phone, street, postalCode, city, hourlyRate ->
-                viewModel.updateStudio(studio, name, contactPerson, email, phone, street, postalCode, city, hourlyRate)
+            onSave = { name, contactPerson, email, phone, street, postalCode, city, hourlyRate, invoiceReference ->
+                viewModel.updateStudio(studio, name, contactPerson, email, phone, street, postalCode, city, hourlyRate, invoiceReference)
             }
         )
     }
+    
+    // Delete confirmation dialog
+    uiState.deletingStudio?.let { studio ->
+        StudioDeleteDialog(
+            studio = studio,
+            preview = uiState.deletePreview,
+            onDismiss = { viewModel.cancelDeleteStudio() },
+            onConfirm = { viewModel.deleteStudio(studio) }
+        )
+    }
 }
 
 @OptIn(ExperimentalMaterial3Api::class)
@@ -194,8 +205,6 @@ private fun StudioCard(
     onToggleActive: () -> Unit,
     onDelete: () -> Unit
 ) {
-    var showDeleteDialog by remember { mutableStateOf(false) }
-    
     Card(
         modifier = Modifier
             .fillMaxWidth()
@@ -355,7 +364,7 @@ private fun StudioCard(
                             },
                             onClick = {
                                 expanded = false
-                                showDeleteDialog = true
+                                onDelete()
                             },
                             leadingIcon = {
                                 Icon(
@@ -370,33 +379,65 @@ private fun StudioCard(
             }
         }
     }
-    
-    // Delete confirmation dialog
-    if (showDeleteDialog) {
-        AlertDialog(
-            onDismissRequest = { showDeleteDialog = false },
-            title = { Text("Studio löschen?") },
-            text = { 
+}
+
+@Composable
+private fun StudioDeleteDialog(
+    studio: Studio,
+    preview: StudioDeletePreview?,
+    onDismiss: () -> Unit,
+    onConfirm: () -> Unit
+) {
+    AlertDialog(
+        onDismissRequest = onDismiss,
+        title = { Text("Studio löschen?") },
+        text = { 
+            Column(verticalArrangement = Arrangement.spacedBy(8.dp)) {
                 Text("Möchtest du das Studio \"${studio.name}\" wirklich löschen? Diese Aktion kann nicht rückgängig gemacht werden.")
-            },
-            confirmButton = {
-                TextButton(
-                    onClick = {
-                        showDeleteDialog = false
-                        onDelete()
-                    },
-                    colors = ButtonDefaults.textButtonColors(
-                        contentColor = MaterialTheme.colorScheme.error
+                if (preview == null) {
+                    Text(
+                        "Zugehörige Kurse, Vorlagen und Rechnungen werden ebenfalls gelöscht.",
+                        color = MaterialTheme.colorScheme.error
+                    )
+                } else if (preview.hasDependents) {
+                    Text(
+                        "Dabei werden auch gelöscht:",
+                        fontWeight = FontWeight.Bold
+                    )
+                    if (preview.classCount > 0) {
+                        Text("• ${preview.classCount} Kurse")
+                    }
+                    if (preview.templateCount > 0) {
+                        Text("• ${preview.templateCount} Vorlagen")
+                    }
+                    if (preview.invoiceCount > 0) {
+                        Text(
+                            "• ${preview.invoiceCount} Rechnungen",
+                            color = MaterialTheme.colorScheme.error
+                        )
+                    }
+                    Text(
+                        "Tipp: Deaktiviere das Studio, um die Daten zu behalten.",
+                        style = MaterialTheme.typography.bodySmall,
+                        color = MaterialTheme.colorScheme.onSurfaceVariant
                     )
-                ) {
-                    Text("Löschen")
-                }
-            },
-            dismissButton = {
-                TextButton(onClick = { showDeleteDialog = false }) {
-                    Text("Abbrechen")
                 }
             }
-        )
-    }
+        },
+        confirmButton = {
+            TextButton(
+                onClick = onConfirm,
+                colors = ButtonDefaults.textButtonColors(
+                    contentColor = MaterialTheme.colorScheme.error
+                )
+            ) {
+                Text("Löschen")
+            }
+        },
+        dismissButton = {
+            TextButton(onClick = onDismiss) {
+                Text("Abbrechen")
+            }
+        }
+    )
 }
@@ -2,7 +2,9 @@ package de.yogaknete.app.presentation.screens.week
 
 import androidx.compose.foundation.BorderStroke
 import androidx.compose.foundation.clickable
+import androidx.compose.foundation.horizontalScroll
 import androidx.compose.foundation.layout.*
+import androidx.compose.foundation.rememberScrollState
 import androidx.compose.material.icons.Icons
 import androidx.compose.material.icons.automirrored.filled.ArrowBack
 import androidx.compose.material.icons.automirrored.filled.ArrowForward
@@ -45,11 +47,14 @@ fun AddClassDialog(
     studios: List<Studio>,
     weekDays: List<LocalDate>,
     templates: List<ClassTemplate>,
+    titleSuggestions: Map<Long, List<String>> = emptyMap(),
+    errorMessage: String? = null,
     onDismiss: () -> Unit,
     onConfirm: (studioId: Long, title: String, date: LocalDate, startHour: Int, startMinute: Int, endHour: Int, endMinute: Int) -> Unit
 ) {
     var selectedStudioId by remember { mutableLongStateOf(studios.firstOrNull()?.id ?: 0L) }
     var title by remember { mutableStateOf("Hatha Yoga") }
+    var titleTyped by remember { mutableStateOf(false) }
     var selectedDate by remember { mutableStateOf(weekDays.firstOrNull() ?: LocalDate(2024, 1, 1)) }
     var startHour by remember { mutableIntStateOf(17) }
     var startMinute by remember { mutableIntStateOf(30) }
@@ -128,12 +133,34 @@ fun AddClassDialog(
                 // Title input
                 OutlinedTextField(
                     value = title,
-                    onValueChange = { title = it },
+                    onValueChange = {
+                        title = it
+                        titleTyped = true
+                    },
                     label = { Text("Kursname") },
                     placeholder = { Text("z.B. Hatha Yoga") },
                     modifier = Modifier.fillMaxWidth()
                 )
                 
+                // Previously used titles for this studio, most frequent first
+                val titleQuery = if (titleTyped) title.trim() else ""
+                val matchingTitles = titleSuggestions[selectedStudioId].orEmpty()
+                    .filter { it.contains(titleQuery, ignoreCase = true) && it != title }
+                    .take(5)
+                if (matchingTitles.isNotEmpty()) {
+                    Row(
+                        modifier = Modifier.horizontalScroll(rememberScrollState()),
+                        horizontalArrangement = Arrangement.spacedBy(8.dp)
+                    ) {
+                        matchingTitles.forEach { suggestion ->
+                            SuggestionChip(
+                                onClick = { title = suggestion },
+                                label = { Text(suggestion) }
+                            )
+                        }
+                    }
+                }
+                
                 // Date selection with date picker
                 Box(
                     modifier = Modifier
@@ -188,6 +215,14 @@ fun AddClassDialog(
                     color = MaterialTheme.colorScheme.onSurface.copy(alpha = 0.7f),
                     modifier = Modifier.padding(start = 16.dp)
                 )
+                
+                errorMessage?.let {
+                    Text(
+                        text = it,
+                        style = MaterialTheme.typography.bodySmall,
+                        color = MaterialTheme.colorScheme.error
+                    )
+                }
             }
         },
         confirmButton = {
@@ -744,6 +779,7 @@ fun BulkCancelDialog(
 fun EditClassDialog(
     yogaClass: YogaClass,
     studios: List<Studio>,
+    errorMessage: String? = null,
     onDismiss: () -> Unit,
     onConfirm: (date: LocalDate, startHour: Int, startMinute: Int, endHour: Int, endMinute: Int) -> Unit
 ) {
@@ -854,6 +890,14 @@ fun EditClassDialog(
                     color = MaterialTheme.colorScheme.onSurface.copy(alpha = 0.7f),
                     modifier = Modifier.padding(start = 16.dp)
                 )
+                
+                errorMessage?.let {
+                    Text(
+                        text = it,
+                        style = MaterialTheme.typography.bodySmall,
+                        color = MaterialTheme.colorScheme.error
+                    )
+                }
             }
         },
         confirmButton = {
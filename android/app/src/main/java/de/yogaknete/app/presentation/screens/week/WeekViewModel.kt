@@ -34,6 +34,7 @@ data class WeekViewState(
     val classes: Map<LocalDate, List<YogaClass>> = emptyMap(),
     val studios: List<Studio> = emptyList(),
     val templates: List<ClassTemplate> = emptyList(),
+    val titleSuggestions: Map<Long, List<String>> = emptyMap(),
     val totalClassesThisWeek: Int = 0,
     val totalHoursThisWeek: Double = 0.0,
     val earningsPerStudio: Map<Long, Double> = emptyMap(), // Studio ID to earnings
@@ -43,6 +44,7 @@ data class WeekViewState(
     val showQuickAddDialog: Boolean = false,
     val quickAddDate: LocalDate? = null,
     val selectedClass: YogaClass? = null,
+    val duplicateClassMessage: String? = null,
     val showBulkCancelDialog: Boolean = false,
     val showEditClassDialog: Boolean = false,
     val showWeekStats: Boolean = false,
@@ -211,10 +213,19 @@ class WeekViewModel @Inject constructor(
     
     fun showAddClassDialog() {
         _state.update { it.copy(showAddClassDialog = true) }
+        loadTitleSuggestions()
+    }
+    
+    private fun loadTitleSuggestions() {
+        viewModelScope.launch {
+            val suggestions = yogaClassDao.getClassTitleUsages()
+                .groupBy({ it.studioId }, { it.title })
+            _state.update { it.copy(titleSuggestions = suggestions) }
+        }
     }
     
     fun hideAddClassDialog() {
-        _state.update { it.copy(showAddClassDialog = false) }
+        _state.update { it.copy(showAddClassDialog = false, duplicateClassMessage = null) }
     }
     
     fun showQuickAddDialogForDate(date: LocalDate) {
@@ -239,6 +250,9 @@ class WeekViewModel @Inject constructor(
             val endTime = date.atTime(endHour, endMinute)
             val duration = DateUtils.calculateDurationHours(startTime, endTime)
             
+            // Keep the dialog open with the entered data and show the error there
+            if (rejectDuplicate(studioId, startTime)) return@launch
+            
             val newClass = YogaClass(
                 studioId = studioId,
                 title = title,
@@ -254,6 +268,24 @@ class WeekViewModel @Inject constructor(
         }
     }
 
+    /**
+     * A class that is not cancelled at the same studio and start time would be billed twice.
+     * Returns true and sets [WeekViewState.duplicateClassMessage] if such a class exists.
+     */
+    private suspend fun rejectDuplicate(studioId: Long, startTime: LocalDateTime, excludeId: Long = 0): Boolean {
+        val existing = yogaClassDao.getActiveClassAtTime(startTime, studioId, excludeId)
+        _state.update {
+            it.copy(duplicateClassMessage = existing?.let { cls ->
+                "Zu dieser Zeit gibt es im Studio bereits den Kurs „${cls.title}“"
+            })
+        }
+        return existing != null
+    }
+    
+    fun clearDuplicateClassMessage() {
+        _state.update { it.copy(duplicateClassMessage = null) }
+    }
+
     fun openClassById(classId: Long) {
         viewModelScope.launch {
             val yogaClass = yogaClassDao.getClassById(classId)
@@ -320,7 +352,7 @@ class WeekViewModel @Inject constructor(
     }
 
     fun hideEditClassDialog() {
-        _state.update { it.copy(showEditClassDialog = false) }
+        _state.update { it.copy(showEditClassDialog = false, duplicateClassMessage = null) }
     }
 
     fun updateClassSchedule(
@@ -336,6 +368,9 @@ class WeekViewModel @Inject constructor(
             val newStart = newDate.atTime(startHour, startMinute)
             val newEnd = newDate.atTime(endHour, endMinute)
             val newDuration = DateUtils.calculateDurationHours(newStart, newEnd)
+            if (existing.status != ClassStatus.CANCELLED &&
+                rejectDuplicate(existing.studioId, newStart, excludeId = existing.id)
+            ) return@launch
             val updated = existing.copy(
                 startTime = newStart,
                 endTime = newEnd,
@@ -367,6 +402,11 @@ class WeekViewModel @Inject constructor(
                 .plus((duration * 60 * 60).toInt(), DateTimeUnit.SECOND)
                 .toLocalDateTime(TimeZone.currentSystemDefault())
 
+            if (rejectDuplicate(template.studioId, startDateTime)) {
+                hideQuickAddDialog()
+                return@launch
+            }
+
             val newClass = YogaClass(
                 studioId = template.studioId,
                 title = template.className,
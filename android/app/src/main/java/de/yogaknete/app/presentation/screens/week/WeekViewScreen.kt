@@ -59,8 +59,19 @@ fun WeekViewScreen(
 ) {
     val state by viewModel.state.collectAsState()
     val isPastWeek = remember(state.currentWeekEnd) { DateUtils.isPast(state.currentWeekEnd) }
+    val snackbarHostState = remember { SnackbarHostState() }
+    
+    // Add and edit dialogs show the message themselves
+    LaunchedEffect(state.duplicateClassMessage) {
+        if (state.showAddClassDialog || state.showEditClassDialog) return@LaunchedEffect
+        state.duplicateClassMessage?.let {
+            snackbarHostState.showSnackbar(it)
+            viewModel.clearDuplicateClassMessage()
+        }
+    }
     
     Scaffold(
+        snackbarHost = { SnackbarHost(snackbarHostState) },
         topBar = {
             WeekViewTopBar(
                 weekStart = state.currentWeekStart,
@@ -150,6 +161,8 @@ fun WeekViewScreen(
             studios = state.studios,
             weekDays = state.weekDays,
             templates = state.templates,
+            titleSuggestions = state.titleSuggestions,
+            errorMessage = state.duplicateClassMessage,
             onDismiss = { viewModel.hideAddClassDialog() },
             onConfirm = { studioId, title, date, startHour, startMinute, endHour, endMinute ->
                 viewModel.addClass(studioId, title, date, startHour, startMinute, endHour, endMinute)
@@ -206,6 +219,7 @@ fun WeekViewScreen(
         EditClassDialog(
             yogaClass = cls,
             studios = state.studios,
+            errorMessage = state.duplicateClassMessage,
             onDismiss = { viewModel.hideEditClassDialog() },
             onConfirm = { date, startHour, startMinute, endHour, endMinute ->
                 viewModel.updateClassSchedule(
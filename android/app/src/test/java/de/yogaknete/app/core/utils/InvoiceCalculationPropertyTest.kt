@@ -0,0 +1,170 @@
+package de.yogaknete.app.core.utils
+
+import kotlinx.datetime.DateTimeUnit
+import kotlinx.datetime.LocalDate
+import kotlinx.datetime.atTime
+import kotlinx.datetime.plus
+import org.junit.Test
+import org.junit.Assert.*
+import java.math.BigDecimal
+import kotlin.math.abs
+import kotlin.random.Random
+
+/**
+ * Randomized property tests for duration calculation and invoice total aggregation.
+ *
+ * Every run uses the same seed, so a failure can be reproduced from the reported iteration.
+ */
+class InvoiceCalculationPropertyTest {
+
+    private val seed = 20260115
+    private val iterations = 1000
+
+    // Durations as produced by DateUtils.calculateDurationHours: whole minutes, up to 4 hours
+    private fun Random.nextMinutes(): Int = nextInt(0, 4 * 60 + 1)
+
+    // Hourly rates in whole cents, up to 200 EUR
+    private fun Random.nextRateCents(): Int = nextInt(0, 200_00 + 1)
+
+    private fun Random.nextMinuteList(): List<Int> = List(nextInt(0, 40)) { nextMinutes() }
+
+    private fun hours(minutes: Int): Double = minutes / 60.0
+
+    private fun euros(rateCents: Int): Double = rateCents / 100.0
+
+    // Exact rational line amount minutes * rateCents / 60, rounded half-up, in integer arithmetic
+    private fun expectedLineCents(minutes: Int, rateCents: Int): Long {
+        val numerator = minutes.toLong() * rateCents
+        return (2 * numerator + 60) / 120
+    }
+
+    @Test
+    fun `line amount is the exact product rounded half up`() {
+        val random = Random(seed)
+        repeat(iterations) { i ->
+            val minutes = random.nextMinutes()
+            val rateCents = random.nextRateCents()
+
+            assertEquals(
+                "Iteration $i: $minutes min x $rateCents cents/h",
+                expectedLineCents(minutes, rateCents),
+                MoneyUtils.lineAmountCents(hours(minutes), euros(rateCents))
+            )
+        }
+    }
+
+    @Test
+    fun `line amount is exact for every 5 minute step and 10 cent rate`() {
+        for (minutes in 0..4 * 60 step 5) {
+            for (rateCents in 0..200_00 step 10) {
+                assertEquals(
+                    "$minutes min x $rateCents cents/h",
+                    expectedLineCents(minutes, rateCents),
+                    MoneyUtils.lineAmountCents(hours(minutes), euros(rateCents))
+                )
+            }
+        }
+    }
+
+    @Test
+    fun `total deviates from unrounded total by at most half a cent per line`() {
+        val random = Random(seed)
+        repeat(iterations) { i ->
+            val minutes = random.nextMinuteList()
+            val rateCents = random.nextRateCents()
+
+            // Exact total in 1/60 cents: sum(minutes) * rateCents
+            val exactSixtiethCents = minutes.sumOf { it.toLong() } * rateCents
+            val totalCents = MoneyUtils.totalAmountCents(minutes.map { hours(it) }, euros(rateCents))
+
+            assertTrue(
+                "Iteration $i: total $totalCents cents for ${minutes.size} lines at $rateCents cents/h",
+                abs(totalCents * 60 - exactSixtiethCents) <= 30L * minutes.size
+            )
+        }
+    }
+
+    @Test
+    fun `total in euros matches total in cents`() {
+        val random = Random(seed)
+        repeat(iterations) { i ->
+            val durations = random.nextMinuteList().map { hours(it) }
+            val rate = euros(random.nextRateCents())
+
+            val totalCents = MoneyUtils.totalAmountCents(durations, rate)
+
+            assertEquals(
+                "Iteration $i",
+                totalCents,
+                BigDecimal.valueOf(MoneyUtils.totalAmount(durations, rate)).movePointRight(2).longValueExact()
+            )
+        }
+    }
+
+    @Test
+    fun `duration is whole minutes and matches the wall clock difference`() {
+        val random = Random(seed)
+        val day = LocalDate(2026, 1, 15) // no DST transition
+        repeat(iterations) { i ->
+            val startMinute = random.nextInt(6 * 60, 20 * 60)
+            val endMinute = random.nextInt(startMinute, 22 * 60 + 1)
+            val start = day.atTime(startMinute / 60, startMinute % 60)
+            val end = day.atTime(endMinute / 60, endMinute % 60)
+
+            val duration = DateUtils.calculateDurationHours(start, end)
+
+            assertEquals("Iteration $i: $start - $end", (endMinute - startMinute) / 60.0, duration, 1e-9)
+        }
+    }
+
+    @Test
+    fun `duration is additive across consecutive intervals`() {
+        val random = Random(seed)
+        repeat(iterations) { i ->
+            val date = LocalDate(2026, random.nextInt(1, 13), random.nextInt(1, 29))
+            val minutes = List(3) { random.nextInt(0, 24 * 60) }.sorted()
+            val (a, b, c) = minutes.map { date.atTime(it / 60, it % 60) }
+
+            val whole = DateUtils.calculateDurationHours(a, c)
+            val parts = DateUtils.calculateDurationHours(a, b) + DateUtils.calculateDurationHours(b, c)
+
+            assertEquals("Iteration $i: $a - $b - $c", whole, parts, 1e-9)
+        }
+    }
+
+    @Test
+    fun `longer class never costs less`() {
+        val random = Random(seed)
+        repeat(iterations) { i ->
+            val shorter = random.nextMinutes()
+            val longer = random.nextInt(shorter, 4 * 60 + 1)
+            val rate = euros(random.nextRateCents())
+
+            assertTrue(
+                "Iteration $i: $shorter min vs $longer min at $rate EUR",
+                MoneyUtils.lineAmountCents(hours(shorter), rate) <= MoneyUtils.lineAmountCents(hours(longer), rate)
+            )
+        }
+    }
+
+    @Test
+    fun `overnight class ending after midnight has the wall clock duration`() {
+        val random = Random(seed)
+        repeat(iterations) { i ->
+            val date = LocalDate(2026, 1, random.nextInt(1, 31)) // no DST transition
+            val startMinute = random.nextInt(20 * 60, 24 * 60)
+            val endMinute = random.nextInt(0, 3 * 60)
+            val start = date.atTime(startMinute / 60, startMinute % 60)
+            val end = date.plus(1, DateTimeUnit.DAY).atTime(endMinute / 60, endMinute % 60)
+
+            val duration = DateUtils.calculateDurationHours(start, end)
+
+            assertEquals(
+                "Iteration $i: $start - $end",
+                (24 * 60 - startMinute + endMinute) / 60.0,
+                duration,
+                1e-9
+            )
+        }
+    }
+}
@@ -0,0 +1,61 @@
+package de.yogaknete.app.core.utils
+
+import org.junit.Test
+import org.junit.Assert.*
+
+/**
+ * Tests for MoneyUtils - invoice line and total amount rounding
+ */
+class MoneyUtilsTest {
+
+    private val twentyMinutes = 1.0 / 3.0
+
+    @Test
+    fun `lineAmountCents multiplies exact durations`() {
+        assertEquals(6750L, MoneyUtils.lineAmountCents(1.5, 45.0))
+    }
+
+    @Test
+    fun `lineAmountCents rounds half up to whole cents`() {
+        // 20 min at 50 EUR = 16.666... EUR
+        assertEquals(1667L, MoneyUtils.lineAmountCents(twentyMinutes, 50.0))
+        // 15 min at 0.50 EUR = 0.125 EUR
+        assertEquals(13L, MoneyUtils.lineAmountCents(0.25, 0.5))
+    }
+
+    @Test
+    fun `lineAmountCents rounds exact half cent of repeating durations up`() {
+        // 55 min at 22.50 EUR = exactly 20.625 EUR, duration stored as 0.91666... h
+        assertEquals(2063L, MoneyUtils.lineAmountCents(55 / 60.0, 22.5))
+    }
+
+    @Test
+    fun `total of three third-hour lines equals sum of rounded lines`() {
+        val durations = listOf(twentyMinutes, twentyMinutes, twentyMinutes)
+
+        val totalCents = MoneyUtils.totalAmountCents(durations, 50.0)
+
+        // 3 x 16.67 EUR, not 1h x 50 EUR
+        assertEquals(5001L, totalCents)
+        assertEquals(50.01, MoneyUtils.totalAmount(durations, 50.0), 0.0)
+    }
+
+    @Test
+    fun `total of three third-hour lines rounding down`() {
+        val durations = listOf(twentyMinutes, twentyMinutes, twentyMinutes)
+
+        // 3 x 13.33 EUR
+        assertEquals(3999L, MoneyUtils.totalAmountCents(durations, 40.0))
+    }
+
+    @Test
+    fun `total of empty list is zero`() {
+        assertEquals(0L, MoneyUtils.totalAmountCents(emptyList(), 45.0))
+    }
+
+    @Test
+    fun `centsToEuros converts without float drift`() {
+        assertEquals(0.1, MoneyUtils.centsToEuros(10L), 0.0)
+        assertEquals(123.45, MoneyUtils.centsToEuros(12345L), 0.0)
+    }
+}
@@ -0,0 +1,83 @@
+package de.yogaknete.app.data.repository
+
+import de.yogaknete.app.data.local.InvoiceDao
+import de.yogaknete.app.domain.model.InvoiceSummary
+import de.yogaknete.app.domain.model.YogaClass
+import io.mockk.*
+import kotlinx.coroutines.test.runTest
+import kotlinx.datetime.LocalDateTime
+import org.junit.Assert.*
+import org.junit.Before
+import org.junit.Test
+
+class InvoiceRepositoryImplTest {
+    
+    private lateinit var invoiceDao: InvoiceDao
+    private lateinit var repository: InvoiceRepositoryImpl
+    
+    @Before
+    fun setup() {
+        invoiceDao = mockk()
+        repository = InvoiceRepositoryImpl(invoiceDao)
+    }
+    
+    @Test
+    fun `getInvoiceSummariesForMonth uses sum of rounded line amounts`() = runTest {
+        // Given - two 55 minute classes at 22.50 EUR, 20.625 EUR each
+        val summary = InvoiceSummary(
+            studioId = 1,
+            studioName = "Yoga Studio Zen",
+            month = 3,
+            year = 2025,
+            totalHours = 110 / 60.0,
+            completedClasses = 2,
+            hourlyRate = 22.5,
+            totalAmount = 0.0
+        )
+        coEvery { invoiceDao.getInvoiceSummariesForMonth(3, 2025) } returns listOf(summary)
+        coEvery { invoiceDao.getClassesForInvoice(1, 3, 2025) } returns listOf(
+            createTestClass(id = 1, day = 4),
+            createTestClass(id = 2, day = 11)
+        )
+        
+        // When
+        val result = repository.getInvoiceSummariesForMonth(3, 2025)
+        
+        // Then - 2 x 20.63 EUR, as printed on the invoice
+        assertEquals(41.26, result.single().totalAmount, 0.0)
+    }
+    
+    @Test
+    fun `getInvoiceSummariesForMonth keeps zero amount for invoice without classes`() = runTest {
+        // Given
+        val summary = InvoiceSummary(
+            studioId = 2,
+            studioName = "Studio Ohne Kurse",
+            month = 3,
+            year = 2025,
+            totalHours = 0.0,
+            completedClasses = 0,
+            hourlyRate = 45.0,
+            totalAmount = 0.0,
+            hasExistingInvoice = true,
+            invoiceId = 5
+        )
+        coEvery { invoiceDao.getInvoiceSummariesForMonth(3, 2025) } returns listOf(summary)
+        coEvery { invoiceDao.getClassesForInvoice(2, 3, 2025) } returns emptyList()
+        
+        // When
+        val result = repository.getInvoiceSummariesForMonth(3, 2025)
+        
+        // Then
+        assertEquals(summary, result.single())
+    }
+    
+    private fun createTestClass(id: Long, day: Int) = YogaClass(
+        id = id,
+        studioId = 1,
+        title = "Hatha Yoga",
+        startTime = LocalDateTime(2025, 3, day, 9, 0),
+        endTime = LocalDateTime(2025, 3, day, 9, 55),
+        durationHours = 55 / 60.0
+    )
+}
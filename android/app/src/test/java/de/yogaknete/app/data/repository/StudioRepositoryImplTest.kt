@@ -2,6 +2,7 @@ package de.yogaknete.app.data.repository
 
 import de.yogaknete.app.data.local.StudioDao
 import de.yogaknete.app.domain.model.Studio
+import de.yogaknete.app.domain.model.StudioDeletePreview
 import io.mockk.*
 import kotlinx.coroutines.flow.flowOf
 import kotlinx.coroutines.flow.first
@@ -182,6 +183,28 @@ class StudioRepositoryImplTest {
         coVerify { studioDao.deleteAllStudios() }
     }
     
+    @Test
+    fun `getDeletePreview returns dependent counts from dao`() = runTest {
+        // Given
+        val preview = StudioDeletePreview(classCount = 12, templateCount = 2, invoiceCount = 3)
+        coEvery { studioDao.getDeletePreview(1L) } returns preview
+        
+        // When
+        val result = repository.getDeletePreview(1L)
+        
+        // Then
+        assertEquals(preview, result)
+        assertTrue(result.hasDependents)
+        coVerify { studioDao.getDeletePreview(1L) }
+    }
+    
+    @Test
+    fun `delete preview without dependents`() {
+        val preview = StudioDeletePreview(classCount = 0, templateCount = 0, invoiceCount = 0)
+        
+        assertFalse(preview.hasDependents)
+    }
+    
     // Helper function to create test studio
     private fun createTestStudio(
         id: Long = 0,
@@ -120,6 +120,21 @@ class YogaClassRepositoryImplTest {
         coVerify { yogaClassDao.getClassById(classId) }
     }
     
+    @Test
+    fun `getActiveClassAtTime returns non-cancelled class from dao`() = runTest {
+        // Given
+        val existingClass = createTestYogaClass(id = 3, studioId = 2)
+        
+        coEvery { yogaClassDao.getActiveClassAtTime(existingClass.startTime, 2, 0) } returns existingClass
+        
+        // When
+        val result = repository.getActiveClassAtTime(existingClass.startTime, 2)
+        
+        // Then
+        assertEquals(existingClass, result)
+        coVerify { yogaClassDao.getActiveClassAtTime(existingClass.startTime, 2, 0) }
+    }
+    
     @Test
     fun `getClassesForInvoice retrieves classes for studio and month`() = runTest {
         // Given
@@ -0,0 +1,185 @@
+package de.yogaknete.app.domain.service
+
+import de.yogaknete.app.data.local.entities.ClassTemplate
+import de.yogaknete.app.domain.model.BackupData
+import de.yogaknete.app.domain.model.Invoice
+import de.yogaknete.app.domain.model.Studio
+import de.yogaknete.app.domain.model.UserProfile
+import de.yogaknete.app.domain.model.YogaClass
+import kotlinx.datetime.DayOfWeek
+import kotlinx.datetime.Instant
+import kotlinx.datetime.LocalDateTime
+import kotlinx.datetime.LocalTime
+import org.junit.Assert.*
+import org.junit.Before
+import org.junit.Test
+
+class BackupAnonymizerTest {
+
+    private lateinit var anonymizer: BackupAnonymizer
+
+    private val profile = UserProfile(
+        name = "Anna Yoga",
+        street = "Yogastraße 1",
+        postalCode = "80331",
+        city = "München",
+        taxId = "123/456/78901",
+        phone = "0170 1234567",
+        email = "anna@yoga.de",
+        bankName = "Sparkasse München",
+        iban = "DE02120300000000202051",
+        bic = "BYLADEM1001",
+        defaultHourlyRate = 45.0,
+        isOnboardingComplete = true
+    )
+
+    private val studio = Studio(
+        id = 7,
+        name = "Yoga Studio Zen",
+        contactPerson = "Max Müller",
+        email = "info@zen.de",
+        phone = "089 123456",
+        street = "Hauptstraße 10",
+        postalCode = "80333",
+        city = "München",
+        hourlyRate = 45.0
+    )
+
+    private val yogaClass = YogaClass(
+        id = 3,
+        studioId = 7,
+        title = "Hatha Yoga",
+        startTime = LocalDateTime(2026, 1, 7, 9, 0),
+        endTime = LocalDateTime(2026, 1, 7, 10, 30),
+        durationHours = 1.5,
+        notes = "Frau Schmidt hatte Rückenschmerzen"
+    )
+
+    private val template = ClassTemplate(
+        id = 2,
+        name = "Montag bei Max im Zen",
+        studioId = 7,
+        className = "Hatha Yoga",
+        dayOfWeek = DayOfWeek.MONDAY,
+        startTime = LocalTime(9, 0),
+        endTime = LocalTime(10, 30),
+        duration = 1.5,
+        autoSchedule = true
+    )
+
+    private val invoice = Invoice(
+        id = 1,
+        studioId = 7,
+        invoiceNumber = "2026-001",
+        month = 1,
+        year = 2026,
+        totalHours = 1.5,
+        hourlyRate = 45.0,
+        totalAmount = 67.5,
+        createdAt = LocalDateTime(2026, 1, 31, 10, 0),
+        notes = "Rechnung an Max persönlich übergeben",
+        pdfPath = "/storage/emulated/0/Download/Rechnung.pdf"
+    )
+
+    private val backupData = BackupData(
+        exportDate = Instant.parse("2026-02-01T10:00:00Z"),
+        userProfile = profile,
+        studios = listOf(studio),
+        classes = listOf(yogaClass),
+        templates = listOf(template),
+        invoices = listOf(invoice)
+    )
+
+    @Before
+    fun setUp() {
+        anonymizer = BackupAnonymizer()
+    }
+
+    @Test
+    fun `profile personal and bank data is replaced`() {
+        val result = anonymizer.anonymize(backupData).userProfile!!
+
+        assertEquals("Erika Mustermann", result.name)
+        assertEquals("Musterstraße 1", result.street)
+        assertEquals("erika@example.com", result.email)
+        assertEquals(BackupAnonymizer.PLACEHOLDER_IBAN, result.iban)
+        assertEquals(BackupAnonymizer.PLACEHOLDER_BIC, result.bic)
+        assertNotEquals(profile.taxId, result.taxId)
+        assertNotEquals(profile.phone, result.phone)
+        assertNotEquals(profile.bankName, result.bankName)
+    }
+
+    @Test
+    fun `profile rate and onboarding state are kept`() {
+        val result = anonymizer.anonymize(backupData).userProfile!!
+
+        assertEquals(45.0, result.defaultHourlyRate, 0.0)
+        assertTrue(result.isOnboardingComplete)
+    }
+
+    @Test
+    fun `empty profile fields stay empty`() {
+        val sparse = backupData.copy(userProfile = profile.copy(bic = "", phone = "", taxId = ""))
+
+        val result = anonymizer.anonymize(sparse).userProfile!!
+
+        assertEquals("", result.bic)
+        assertEquals("", result.phone)
+        assertEquals("", result.taxId)
+    }
+
+    @Test
+    fun `missing profile stays missing`() {
+        val result = anonymizer.anonymize(backupData.copy(userProfile = null))
+
+        assertNull(result.userProfile)
+    }
+
+    @Test
+    fun `studio contact data is replaced but id and rate are kept`() {
+        val result = anonymizer.anonymize(backupData).studios.single()
+
+        assertEquals("Studio 1", result.name)
+        assertEquals("Kontakt 1", result.contactPerson)
+        assertEquals("studio1@example.com", result.email)
+        assertFalse(result.street.contains("Hauptstraße"))
+        assertEquals(7L, result.id)
+        assertEquals(45.0, result.hourlyRate, 0.0)
+    }
+
+    @Test
+    fun `class title and notes are replaced but schedule is kept`() {
+        val result = anonymizer.anonymize(backupData).classes.single()
+
+        assertEquals("Kurs 1", result.title)
+        assertEquals("", result.notes)
+        assertEquals(yogaClass.copy(title = "Kurs 1", notes = ""), result)
+    }
+
+    @Test
+    fun `template name is replaced and class name matches anonymized class title`() {
+        val result = anonymizer.anonymize(backupData)
+        val anonymizedTemplate = result.templates.single()
+
+        assertEquals("Vorlage 1", anonymizedTemplate.name)
+        assertEquals(result.classes.single().title, anonymizedTemplate.className)
+        assertEquals(template.copy(name = "Vorlage 1", className = "Kurs 1"), anonymizedTemplate)
+    }
+
+    @Test
+    fun `different class titles get different placeholders`() {
+        val second = yogaClass.copy(id = 4, title = "Yin Yoga")
+        val result = anonymizer.anonymize(backupData.copy(classes = listOf(yogaClass, second)))
+
+        assertEquals(listOf("Kurs 1", "Kurs 2"), result.classes.map { it.title })
+    }
+
+    @Test
+    fun `invoice notes and file path are cleared but amounts are kept`() {
+        val result = anonymizer.anonymize(backupData).invoices.single()
+
+        assertEquals("", result.notes)
+        assertNull(result.pdfPath)
+        assertEquals(invoice.copy(notes = "", pdfPath = null), result)
+    }
+}
@@ -9,6 +9,7 @@ import kotlinx.datetime.LocalDateTime
 import org.junit.Assert.*
 import org.junit.Before
 import org.junit.Test
+import java.util.Locale
 
 class InvoiceHtmlGeneratorTest {
 
@@ -358,6 +359,63 @@ class InvoiceHtmlGeneratorTest {
         assertTrue(html.contains("Rechnungsdatum:"))
     }
 
+    @Test
+    fun `meta table contains Ihre Referenz when set`() {
+        val invoice = testInvoice.copy(reference = "PO-4711")
+        val html = generator.generateInvoiceHtml(invoice, testProfile, testStudio, testClasses)
+
+        val metaBlock = html.substringAfter("meta-table").substringBefore("</table>")
+        assertTrue(metaBlock.contains("<td>Ihre Referenz:</td><td>PO-4711</td>"))
+    }
+
+    @Test
+    fun `meta table omits Ihre Referenz when empty`() {
+        val html = generator.generateInvoiceHtml(testInvoice, testProfile, testStudio, testClasses)
+
+        assertFalse(html.contains("Ihre Referenz"))
+    }
+
+    @Test
+    fun `meta table contains Leistungszeitraum for the invoiced month`() {
+        val html = generator.generateInvoiceHtml(testInvoice, testProfile, testStudio, testClasses)
+
+        val metaBlock = html.substringAfter("meta-table").substringBefore("</table>")
+        assertTrue(metaBlock.contains("Leistungszeitraum:"))
+        assertTrue(metaBlock.contains("01.01.–31.01.2026"))
+    }
+
+    @Test
+    fun `Leistungszeitraum handles short and leap-year months`() {
+        val february = testInvoice.copy(month = 2, year = 2028)
+        val html = generator.generateInvoiceHtml(february, testProfile, testStudio, testClasses)
+
+        assertTrue(html.contains("01.02.–29.02.2028"))
+
+        val april = testInvoice.copy(month = 4, year = 2026)
+        assertTrue(generator.generateInvoiceHtml(april, testProfile, testStudio, testClasses).contains("01.04.–30.04.2026"))
+    }
+
+    @Test
+    fun `Leistungszeitraum for December ends on the 31st`() {
+        val december = testInvoice.copy(month = 12, year = 2025)
+        val html = generator.generateInvoiceHtml(december, testProfile, testStudio, testClasses)
+
+        assertTrue(html.contains("01.12.–31.12.2025"))
+    }
+
+    @Test
+    fun `Leistungszeitraum uses Latin digits regardless of device locale`() {
+        val defaultLocale = Locale.getDefault()
+        try {
+            Locale.setDefault(Locale.forLanguageTag("fa-IR"))
+            val html = generator.generateInvoiceHtml(testInvoice, testProfile, testStudio, testClasses)
+
+            assertTrue(html.contains("01.01.–31.01.2026"))
+        } finally {
+            Locale.setDefault(defaultLocale)
+        }
+    }
+
     // --- Services description ---
 
     @Test
@@ -475,6 +533,61 @@ class InvoiceHtmlGeneratorTest {
         assertTrue(gesamtBlock.contains("135"))
     }
 
+    @Test
+    fun `customer note is printed above payment section`() {
+        val invoice = testInvoice.copy(customerNote = "Vielen Dank!\nBis nächsten Monat")
+        val html = generator.generateInvoiceHtml(invoice, testProfile, testStudio, testClasses)
+
+        val beforePayment = html.substringAfter("Gesamtbetrag:").substringBefore("""<div class="payment-section">""")
+        assertTrue(beforePayment.contains("""<div class="customer-note">Vielen Dank!<br>Bis nächsten Monat</div>"""))
+    }
+
+    @Test
+    fun `internal notes are not printed`() {
+        val invoice = testInvoice.copy(notes = "Intern: Max hat zu spät bezahlt")
+        val html = generator.generateInvoiceHtml(invoice, testProfile, testStudio, testClasses)
+
+        assertFalse(html.contains("Intern:"))
+        assertFalse(html.contains("""<div class="customer-note">"""))
+    }
+
+    @Test
+    fun `Kleinunternehmer notice is shown when flag is set`() {
+        val profile = testProfile.copy(isKleinunternehmer = true)
+        val html = generator.generateInvoiceHtml(testInvoice, profile, testStudio, testClasses)
+
+        assertTrue(html.contains("§ 19 UStG"))
+        val afterTotals = html.substringAfter("Gesamtbetrag:")
+        assertTrue(afterTotals.contains(InvoiceHtmlGenerator.KLEINUNTERNEHMER_NOTICE))
+    }
+
+    @Test
+    fun `Kleinunternehmer notice is omitted by default`() {
+        val html = generator.generateInvoiceHtml(testInvoice, testProfile, testStudio, testClasses)
+
+        assertFalse(html.contains("§ 19 UStG"))
+    }
+
+    @Test
+    fun `Gesamtbetrag equals sum of rounded line amounts`() {
+        val thirdHourClasses = (1..3).map { day ->
+            YogaClass(
+                id = day.toLong(), studioId = 1, title = "Yoga",
+                startTime = LocalDateTime(2026, 1, day, 9, 0),
+                endTime = LocalDateTime(2026, 1, day, 9, 20),
+                durationHours = 1.0 / 3.0
+            )
+        }
+        // Naive total would be 1h * 50 EUR = 50.00 EUR
+        val invoice = testInvoice.copy(totalHours = 1.0, hourlyRate = 50.0, totalAmount = 50.0)
+        val html = generator.generateInvoiceHtml(invoice, testProfile, testStudio, thirdHourClasses)
+
+        val tbody = html.substringAfter("<tbody>").substringBefore("</tbody>")
+        assertEquals(3, Regex("16[,.]67 €").findAll(tbody).count())
+        val gesamtBlock = html.substringAfter("Gesamtbetrag:").substringBefore("</tr>")
+        assertTrue(gesamtBlock.contains("50,01 €") || gesamtBlock.contains("50.01 €"))
+    }
+
     @Test
     fun `Gesamtbetrag row has total-row class for bold styling`() {
         val html = generator.generateInvoiceHtml(testInvoice, testProfile, testStudio, testClasses)
@@ -594,4 +707,24 @@ class InvoiceHtmlGeneratorTest {
             footerBlock.contains("Ref: ${testInvoice.invoiceNumber}")
         )
     }
+
+    @Test
+    fun `payment footer contains legal sender data`() {
+        val html = generator.generateInvoiceHtml(testInvoice, testProfile, testStudio, testClasses)
+
+        val legalFooter = html.substringAfter("""<span class="legal-footer">""").substringBefore("</span>")
+        assertEquals(
+            "Anna Yoga &middot; Yogastraße 1 &middot; 80331 München &middot; Steuernummer: 123/456/78901",
+            legalFooter
+        )
+    }
+
+    @Test
+    fun `legal footer skips empty profile fields`() {
+        val profile = testProfile.copy(street = "", taxId = "")
+        val html = generator.generateInvoiceHtml(testInvoice, profile, testStudio, testClasses)
+
+        val legalFooter = html.substringAfter("""<span class="legal-footer">""").substringBefore("</span>")
+        assertEquals("Anna Yoga &middot; 80331 München", legalFooter)
+    }
 }
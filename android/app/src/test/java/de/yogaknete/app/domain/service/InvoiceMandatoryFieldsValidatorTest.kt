@@ -0,0 +1,75 @@
+package de.yogaknete.app.domain.service
+
+import de.yogaknete.app.domain.model.Studio
+import de.yogaknete.app.domain.model.UserProfile
+import org.junit.Assert.*
+import org.junit.Before
+import org.junit.Test
+
+class InvoiceMandatoryFieldsValidatorTest {
+
+    private lateinit var validator: InvoiceMandatoryFieldsValidator
+
+    private val completeProfile = UserProfile(
+        name = "Anna Yoga",
+        street = "Yogastraße 1",
+        postalCode = "80331",
+        city = "München",
+        taxId = "123/456/78901",
+        defaultHourlyRate = 45.0
+    )
+
+    private val completeStudio = Studio(
+        id = 1,
+        name = "Yoga Studio Zen",
+        street = "Hauptstraße 10",
+        postalCode = "80333",
+        city = "München",
+        hourlyRate = 45.0
+    )
+
+    @Before
+    fun setUp() {
+        validator = InvoiceMandatoryFieldsValidator()
+    }
+
+    @Test
+    fun `complete profile and studio have no missing fields`() {
+        val missing = validator.findMissingFields(completeProfile, completeStudio)
+
+        assertTrue(missing.isEmpty())
+    }
+
+    @Test
+    fun `optional contact fields are not required`() {
+        val profile = completeProfile.copy(phone = "", email = "", bankName = "", bic = "")
+        val studio = completeStudio.copy(contactPerson = "", email = "", phone = "")
+
+        assertTrue(validator.findMissingFields(profile, studio).isEmpty())
+    }
+
+    @Test
+    fun `missing tax id is reported`() {
+        val missing = validator.findMissingFields(completeProfile.copy(taxId = ""), completeStudio)
+
+        assertEquals(listOf("Steuernummer oder USt-IdNr."), missing)
+    }
+
+    @Test
+    fun `missing sender address is reported per field`() {
+        val profile = completeProfile.copy(street = "", postalCode = "", city = "")
+
+        val missing = validator.findMissingFields(profile, completeStudio)
+
+        assertEquals(listOf("Deine Straße", "Deine PLZ", "Dein Ort"), missing)
+    }
+
+    @Test
+    fun `missing studio address is reported`() {
+        val studio = completeStudio.copy(street = " ", city = "")
+
+        val missing = validator.findMissingFields(completeProfile, studio)
+
+        assertEquals(listOf("Straße des Studios", "Ort des Studios"), missing)
+    }
+}
@@ -0,0 +1,45 @@
+package de.yogaknete.app.domain.service
+
+import de.yogaknete.app.domain.model.Invoice
+import de.yogaknete.app.domain.model.Studio
+import kotlinx.datetime.LocalDateTime
+import org.junit.Assert.*
+import org.junit.Test
+
+class InvoicePdfServiceTest {
+
+    private val invoice = Invoice(
+        id = 1,
+        studioId = 1,
+        invoiceNumber = "2026-001",
+        month = 1,
+        year = 2026,
+        totalHours = 3.0,
+        hourlyRate = 45.0,
+        totalAmount = 135.0,
+        createdAt = LocalDateTime(2026, 1, 31, 10, 0)
+    )
+
+    private val studio = Studio(id = 1, name = "Yoga Studio Zen", hourlyRate = 45.0)
+
+    @Test
+    fun `document name contains invoice number and studio name`() {
+        val name = InvoicePdfService.documentName(invoice, studio)
+
+        assertEquals("Rechnung_2026-001_Yoga_Studio_Zen", name)
+    }
+
+    @Test
+    fun `document name keeps umlauts and replaces unsafe characters`() {
+        val name = InvoicePdfService.documentName(invoice, studio.copy(name = "Yoga & Mehr: Füssen/Süd"))
+
+        assertEquals("Rechnung_2026-001_Yoga_Mehr_Füssen_Süd", name)
+    }
+
+    @Test
+    fun `document name omits empty studio part`() {
+        val name = InvoicePdfService.documentName(invoice, studio.copy(name = " ?! "))
+
+        assertEquals("Rechnung_2026-001", name)
+    }
+}
@@ -2,6 +2,7 @@ package de.yogaknete.app.presentation.screens.week
 
 import de.yogaknete.app.data.local.YogaClassDao
 import de.yogaknete.app.domain.model.ClassStatus
+import de.yogaknete.app.domain.model.ClassTitleUsage
 import de.yogaknete.app.domain.model.Studio
 import de.yogaknete.app.domain.model.YogaClass
 import de.yogaknete.app.domain.repository.StudioRepository
@@ -46,6 +47,7 @@ class WeekViewModelTest {
         every { yogaClassDao.getClassesInRange(any(), any()) } returns flowOf(emptyList())
         every { studioRepository.getAllActiveStudios() } returns flowOf(emptyList())
         every { classTemplateRepository.getAllActiveTemplates() } returns flowOf(emptyList())
+        coEvery { yogaClassDao.getActiveClassAtTime(any(), any(), any()) } returns null
     }
 
     @After
@@ -428,4 +430,135 @@ class WeekViewModelTest {
 
         verify { notificationScheduler.schedule(match { it.id == 42L }) }
     }
+
+    @Test
+    fun `showAddClassDialog loads title suggestions grouped by studio`() = runTest {
+        coEvery { yogaClassDao.getClassTitleUsages() } returns listOf(
+            ClassTitleUsage(studioId = 1, title = "Vinyasa Flow", usageCount = 12),
+            ClassTitleUsage(studioId = 2, title = "Yin Yoga", usageCount = 5),
+            ClassTitleUsage(studioId = 1, title = "Hatha Yoga", usageCount = 3)
+        )
+        viewModel = createViewModel()
+
+        viewModel.showAddClassDialog()
+        advanceUntilIdle()
+
+        val suggestions = viewModel.state.value.titleSuggestions
+        assertTrue(viewModel.state.value.showAddClassDialog)
+        assertEquals(listOf("Vinyasa Flow", "Hatha Yoga"), suggestions[1L])
+        assertEquals(listOf("Yin Yoga"), suggestions[2L])
+    }
+
+    @Test
+    fun `addClass does not insert duplicate and keeps dialog open`() = runTest {
+        val existing = YogaClass(
+            id = 7, studioId = 1, title = "Hatha Yoga",
+            startTime = LocalDateTime(2024, 11, 4, 17, 30),
+            endTime = LocalDateTime(2024, 11, 4, 18, 45),
+            durationHours = 1.25
+        )
+        coEvery { yogaClassDao.getActiveClassAtTime(existing.startTime, 1L, 0L) } returns existing
+        coEvery { yogaClassDao.getClassTitleUsages() } returns emptyList()
+        viewModel = createViewModel()
+        viewModel.showAddClassDialog()
+
+        viewModel.addClass(
+            studioId = 1L,
+            title = "Hatha Yoga",
+            date = LocalDate(2024, 11, 4),
+            startHour = 17,
+            startMinute = 30,
+            endHour = 18,
+            endMinute = 45
+        )
+        advanceUntilIdle()
+
+        coVerify(exactly = 0) { yogaClassDao.insertClass(any()) }
+        assertNotNull(viewModel.state.value.duplicateClassMessage)
+        assertTrue(viewModel.state.value.showAddClassDialog)
+
+        viewModel.hideAddClassDialog()
+        assertNull(viewModel.state.value.duplicateClassMessage)
+    }
+
+    @Test
+    fun `updateClassSchedule does not move class onto occupied slot`() = runTest {
+        val existing = YogaClass(
+            id = 42L, studioId = 1L, title = "Test Yoga",
+            startTime = LocalDateTime(2024, 11, 4, 17, 30),
+            endTime = LocalDateTime(2024, 11, 4, 18, 45),
+            durationHours = 1.25
+        )
+        val other = existing.copy(id = 43L, startTime = LocalDateTime(2024, 11, 5, 18, 0))
+        coEvery { yogaClassDao.getClassById(42L) } returns existing
+        coEvery { yogaClassDao.getActiveClassAtTime(other.startTime, 1L, 42L) } returns other
+        viewModel = createViewModel()
+
+        viewModel.updateClassSchedule(
+            yogaClassId = 42L,
+            newDate = LocalDate(2024, 11, 5),
+            startHour = 18,
+            startMinute = 0,
+            endHour = 19,
+            endMinute = 15
+        )
+        advanceUntilIdle()
+
+        coVerify(exactly = 0) { yogaClassDao.updateClass(any()) }
+        assertNotNull(viewModel.state.value.duplicateClassMessage)
+    }
+
+    @Test
+    fun `updateClassSchedule excludes the edited class from the duplicate check`() = runTest {
+        val existing = YogaClass(
+            id = 42L, studioId = 1L, title = "Test Yoga",
+            startTime = LocalDateTime(2024, 11, 4, 17, 30),
+            endTime = LocalDateTime(2024, 11, 4, 18, 45),
+            durationHours = 1.25
+        )
+        coEvery { yogaClassDao.getClassById(42L) } returns existing
+        coEvery { yogaClassDao.updateClass(any()) } just Runs
+        viewModel = createViewModel()
+
+        // Only the duration changes, the class keeps its own slot
+        viewModel.updateClassSchedule(
+            yogaClassId = 42L,
+            newDate = LocalDate(2024, 11, 4),
+            startHour = 17,
+            startMinute = 30,
+            endHour = 19,
+            endMinute = 0
+        )
+        advanceUntilIdle()
+
+        coVerify { yogaClassDao.getActiveClassAtTime(existing.startTime, 1L, 42L) }
+        coVerify { yogaClassDao.updateClass(match { it.durationHours == 1.5 }) }
+    }
+
+    @Test
+    fun `createClassFromTemplate does not insert duplicate`() = runTest {
+        val existing = YogaClass(
+            id = 7, studioId = 1, title = "Vinyasa Yoga",
+            startTime = LocalDateTime(2024, 11, 4, 10, 0),
+            endTime = LocalDateTime(2024, 11, 4, 11, 15),
+            durationHours = 1.25,
+            status = ClassStatus.COMPLETED
+        )
+        coEvery { yogaClassDao.getActiveClassAtTime(existing.startTime, 1L, 0L) } returns existing
+        viewModel = createViewModel()
+
+        val template = mockk<de.yogaknete.app.data.local.entities.ClassTemplate> {
+            every { studioId } returns 1L
+            every { className } returns "Vinyasa Yoga"
+            every { startTime } returns LocalTime(10, 0)
+            every { duration } returns 1.25
+            every { id } returns 1L
+        }
+
+        viewModel.createClassFromTemplate(template, LocalDate(2024, 11, 4), markAsCompleted = true)
+        advanceUntilIdle()
+
+        coVerify(exactly = 0) { yogaClassDao.insertClass(any()) }
+        assertNotNull(viewModel.state.value.duplicateClassMessage)
+    }
 }